@@ -0,0 +1,9 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_user_games_user_id_playtime;
+
+ALTER TABLE user_games DROP COLUMN IF EXISTS updated_at;
+ALTER TABLE user_games DROP COLUMN IF EXISTS last_played_at;
+ALTER TABLE user_games DROP COLUMN IF EXISTS playtime_2weeks;
+ALTER TABLE user_games DROP COLUMN IF EXISTS playtime_forever;
+
+DROP INDEX IF EXISTS idx_games_appid_unique;
@@ -0,0 +1,41 @@
+-- Add up migration script here
+-- Games imported from a user's library are created from the appinfo only,
+-- so appid must be unique to upsert them safely.
+-- Existing duplicates are merged into one row per appid first, preferring the
+-- copy created from appdetails (it has a description).
+CREATE TEMP TABLE duplicate_games AS
+SELECT id, FIRST_VALUE(id) OVER (
+    PARTITION BY appid ORDER BY short_description IS NULL, id
+) AS keep_id
+FROM games;
+
+DELETE FROM duplicate_games WHERE id = keep_id;
+
+-- A user linked to several copies keeps a single link, the one already on the kept copy first
+DELETE FROM user_games ug
+USING (
+    SELECT ug.id, ROW_NUMBER() OVER (
+        PARTITION BY ug.user_id, COALESCE(d.keep_id, ug.game_id)
+        ORDER BY d.keep_id IS NOT NULL, ug.id
+    ) AS position
+    FROM user_games ug
+    LEFT JOIN duplicate_games d ON d.id = ug.game_id
+) ranked
+WHERE ranked.id = ug.id AND ranked.position > 1;
+
+UPDATE user_games ug SET game_id = d.keep_id
+FROM duplicate_games d
+WHERE ug.game_id = d.id;
+
+DELETE FROM games g USING duplicate_games d WHERE g.id = d.id;
+
+DROP TABLE duplicate_games;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_games_appid_unique ON games(appid);
+
+ALTER TABLE user_games ADD COLUMN IF NOT EXISTS playtime_forever INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE user_games ADD COLUMN IF NOT EXISTS playtime_2weeks INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE user_games ADD COLUMN IF NOT EXISTS last_played_at TIMESTAMPTZ;
+ALTER TABLE user_games ADD COLUMN IF NOT EXISTS updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+
+CREATE INDEX IF NOT EXISTS idx_user_games_user_id_playtime ON user_games(user_id, playtime_forever DESC);
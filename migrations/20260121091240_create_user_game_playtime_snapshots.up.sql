@@ -0,0 +1,13 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS user_game_playtime_snapshots (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    game_id UUID NOT NULL REFERENCES games(id) ON DELETE CASCADE,
+    snapshot_date DATE NOT NULL DEFAULT CURRENT_DATE,
+    playtime_forever INTEGER NOT NULL,
+    playtime_2weeks INTEGER NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(user_id, game_id, snapshot_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_playtime_snapshots_user_date ON user_game_playtime_snapshots(user_id, snapshot_date);
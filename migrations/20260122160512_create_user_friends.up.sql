@@ -0,0 +1,15 @@
+-- Add up migration script here
+-- Friends are stored by steam id so they can be imported before (or without)
+-- the friend being registered as a user
+CREATE TABLE IF NOT EXISTS user_friends (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    friend_steam_id VARCHAR(255) NOT NULL,
+    relationship VARCHAR(50) NOT NULL,
+    friend_since TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(user_id, friend_steam_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_friends_friend_steam_id ON user_friends(friend_steam_id);
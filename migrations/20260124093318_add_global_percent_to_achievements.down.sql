@@ -0,0 +1,3 @@
+-- Add down migration script here
+ALTER TABLE achievements DROP COLUMN IF EXISTS global_percent_updated_at;
+ALTER TABLE achievements DROP COLUMN IF EXISTS global_percent;
@@ -0,0 +1,3 @@
+-- Add up migration script here
+ALTER TABLE achievements ADD COLUMN IF NOT EXISTS global_percent DOUBLE PRECISION;
+ALTER TABLE achievements ADD COLUMN IF NOT EXISTS global_percent_updated_at TIMESTAMPTZ;
@@ -0,0 +1,32 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS user_bans (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL UNIQUE REFERENCES users(id) ON DELETE CASCADE,
+    vac_banned BOOLEAN NOT NULL,
+    number_of_vac_bans INTEGER NOT NULL,
+    days_since_last_ban INTEGER NOT NULL,
+    number_of_game_bans INTEGER NOT NULL,
+    community_banned BOOLEAN NOT NULL,
+    economy_ban VARCHAR(50) NOT NULL,
+    is_banned BOOLEAN GENERATED ALWAYS AS (
+        vac_banned OR community_banned OR number_of_game_bans > 0 OR economy_ban <> 'none'
+    ) STORED,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- A row is only recorded when the ban status changes
+CREATE TABLE IF NOT EXISTS user_ban_history (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    vac_banned BOOLEAN NOT NULL,
+    number_of_vac_bans INTEGER NOT NULL,
+    days_since_last_ban INTEGER NOT NULL,
+    number_of_game_bans INTEGER NOT NULL,
+    community_banned BOOLEAN NOT NULL,
+    economy_ban VARCHAR(50) NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_bans_is_banned ON user_bans(is_banned);
+CREATE INDEX IF NOT EXISTS idx_user_ban_history_user_recorded_at ON user_ban_history(user_id, recorded_at DESC);
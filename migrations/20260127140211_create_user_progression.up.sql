@@ -0,0 +1,29 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS user_progression (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL UNIQUE REFERENCES users(id) ON DELETE CASCADE,
+    steam_level INTEGER NOT NULL,
+    player_xp INTEGER NOT NULL,
+    xp_needed_to_level_up INTEGER NOT NULL,
+    xp_needed_current_level INTEGER NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Game badges share their badge id across games, appid 0 is used for Steam badges
+CREATE TABLE IF NOT EXISTS user_badges (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    badge_id INTEGER NOT NULL,
+    appid INTEGER NOT NULL DEFAULT 0,
+    level INTEGER NOT NULL,
+    xp INTEGER NOT NULL,
+    scarcity INTEGER NOT NULL,
+    completed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(user_id, badge_id, appid)
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_progression_steam_level ON user_progression(steam_level DESC);
+CREATE INDEX IF NOT EXISTS idx_user_progression_player_xp ON user_progression(player_xp DESC);
@@ -0,0 +1,6 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_inventory_items_inventory_id_name_id;
+DROP INDEX IF EXISTS idx_games_name_id;
+DROP INDEX IF EXISTS idx_users_steam_created_at_id;
+DROP INDEX IF EXISTS idx_users_created_at_id;
+DROP INDEX IF EXISTS idx_users_username_id;
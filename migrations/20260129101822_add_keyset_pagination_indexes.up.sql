@@ -0,0 +1,6 @@
+-- Add up migration script here
+CREATE INDEX IF NOT EXISTS idx_users_username_id ON users(username, id);
+CREATE INDEX IF NOT EXISTS idx_users_created_at_id ON users(created_at, id);
+CREATE INDEX IF NOT EXISTS idx_users_steam_created_at_id ON users(steam_created_at, id);
+CREATE INDEX IF NOT EXISTS idx_games_name_id ON games(name, id);
+CREATE INDEX IF NOT EXISTS idx_inventory_items_inventory_id_name_id ON inventory_items(inventory_id, name, id);
@@ -0,0 +1,6 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_inventory_items_search_vector;
+DROP INDEX IF EXISTS idx_games_search_vector;
+DROP INDEX IF EXISTS idx_inventory_items_name_trgm;
+DROP INDEX IF EXISTS idx_games_name_trgm;
+DROP INDEX IF EXISTS idx_users_username_trgm;
@@ -0,0 +1,15 @@
+-- Add up migration script here
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+-- Trigram indexes back fuzzy matching and the existing ILIKE filters
+CREATE INDEX IF NOT EXISTS idx_users_username_trgm ON users USING GIN (username gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_games_name_trgm ON games USING GIN (name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_inventory_items_name_trgm ON inventory_items USING GIN (name gin_trgm_ops);
+
+-- Expression indexes, queries must use the exact same expressions to hit them
+CREATE INDEX IF NOT EXISTS idx_games_search_vector ON games USING GIN (
+    (setweight(to_tsvector('english', name), 'A') || setweight(to_tsvector('english', COALESCE(short_description, '')), 'B'))
+);
+CREATE INDEX IF NOT EXISTS idx_inventory_items_search_vector ON inventory_items USING GIN (
+    (setweight(to_tsvector('english', name), 'A') || setweight(to_tsvector('english', item_type), 'B'))
+);
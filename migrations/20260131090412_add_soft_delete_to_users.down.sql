@@ -0,0 +1,4 @@
+-- Add down migration script here
+ALTER TABLE inventories DROP CONSTRAINT IF EXISTS fk_inventories_user;
+DROP INDEX IF EXISTS idx_users_deleted_at;
+ALTER TABLE users DROP COLUMN IF EXISTS deleted_at;
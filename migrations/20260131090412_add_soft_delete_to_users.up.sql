@@ -0,0 +1,10 @@
+-- Add up migration script here
+ALTER TABLE users ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_users_deleted_at ON users(deleted_at) WHERE deleted_at IS NOT NULL;
+
+-- Inventories were never tied to users, drop the ones left behind by hard deletes
+DELETE FROM inventories WHERE user_id NOT IN (SELECT id FROM users);
+
+ALTER TABLE inventories
+    ADD CONSTRAINT fk_inventories_user FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE;
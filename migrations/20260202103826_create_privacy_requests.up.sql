@@ -0,0 +1,11 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS privacy_requests (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    steam_id VARCHAR(255) NOT NULL,
+    action VARCHAR(20) NOT NULL,
+    performed_by VARCHAR(255) NOT NULL,
+    rows_affected INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_privacy_requests_steam_id ON privacy_requests(steam_id);
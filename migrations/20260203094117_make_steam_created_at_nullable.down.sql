@@ -0,0 +1,7 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_users_steam_created_at_id;
+CREATE INDEX IF NOT EXISTS idx_users_steam_created_at_id ON users(steam_created_at, id);
+
+UPDATE users SET steam_created_at = 'epoch'::timestamptz WHERE steam_created_at IS NULL;
+
+ALTER TABLE users ALTER COLUMN steam_created_at SET NOT NULL;
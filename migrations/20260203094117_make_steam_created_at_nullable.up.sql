@@ -0,0 +1,10 @@
+-- Add up migration script here
+-- Private profiles do not expose timecreated, it used to be stored as the unix epoch
+ALTER TABLE users ALTER COLUMN steam_created_at DROP NOT NULL;
+
+UPDATE users SET steam_created_at = NULL WHERE steam_created_at = 'epoch'::timestamptz;
+
+-- Keyset pagination sorts on the coalesced value
+DROP INDEX IF EXISTS idx_users_steam_created_at_id;
+CREATE INDEX IF NOT EXISTS idx_users_steam_created_at_id
+    ON users((COALESCE(steam_created_at, 'epoch'::timestamptz)), id);
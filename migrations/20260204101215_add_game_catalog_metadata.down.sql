@@ -0,0 +1,16 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_user_games_game_id;
+DROP INDEX IF EXISTS idx_games_release_date_id;
+DROP TABLE IF EXISTS game_categories;
+DROP TABLE IF EXISTS categories;
+DROP TABLE IF EXISTS game_genres;
+DROP TABLE IF EXISTS genres;
+
+ALTER TABLE games
+    DROP COLUMN IF EXISTS platform_linux,
+    DROP COLUMN IF EXISTS platform_mac,
+    DROP COLUMN IF EXISTS platform_windows,
+    DROP COLUMN IF EXISTS release_date,
+    DROP COLUMN IF EXISTS price_currency,
+    DROP COLUMN IF EXISTS price_final,
+    DROP COLUMN IF EXISTS is_free;
@@ -0,0 +1,41 @@
+-- Add up migration script here
+ALTER TABLE games
+    ADD COLUMN IF NOT EXISTS is_free BOOLEAN NOT NULL DEFAULT false,
+    -- Smallest currency unit, as returned by appdetails price_overview
+    ADD COLUMN IF NOT EXISTS price_final INTEGER,
+    ADD COLUMN IF NOT EXISTS price_currency VARCHAR(10),
+    ADD COLUMN IF NOT EXISTS release_date DATE,
+    ADD COLUMN IF NOT EXISTS platform_windows BOOLEAN NOT NULL DEFAULT false,
+    ADD COLUMN IF NOT EXISTS platform_mac BOOLEAN NOT NULL DEFAULT false,
+    ADD COLUMN IF NOT EXISTS platform_linux BOOLEAN NOT NULL DEFAULT false;
+
+CREATE TABLE IF NOT EXISTS genres (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    steam_genre_id VARCHAR(32) NOT NULL UNIQUE,
+    description VARCHAR(255) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS game_genres (
+    game_id UUID NOT NULL REFERENCES games(id) ON DELETE CASCADE,
+    genre_id UUID NOT NULL REFERENCES genres(id) ON DELETE CASCADE,
+    PRIMARY KEY (game_id, genre_id)
+);
+
+CREATE TABLE IF NOT EXISTS categories (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    steam_category_id INTEGER NOT NULL UNIQUE,
+    description VARCHAR(255) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS game_categories (
+    game_id UUID NOT NULL REFERENCES games(id) ON DELETE CASCADE,
+    category_id UUID NOT NULL REFERENCES categories(id) ON DELETE CASCADE,
+    PRIMARY KEY (game_id, category_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_game_genres_genre_id ON game_genres(genre_id);
+CREATE INDEX IF NOT EXISTS idx_game_categories_category_id ON game_categories(category_id);
+CREATE INDEX IF NOT EXISTS idx_games_release_date_id ON games(release_date, id);
+CREATE INDEX IF NOT EXISTS idx_user_games_game_id ON user_games(game_id);
@@ -0,0 +1,15 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS game_movies;
+DROP TABLE IF EXISTS game_publishers;
+DROP TABLE IF EXISTS publishers;
+DROP TABLE IF EXISTS game_developers;
+DROP TABLE IF EXISTS developers;
+
+ALTER TABLE games
+    DROP COLUMN IF EXISTS background,
+    DROP COLUMN IF EXISTS supported_languages,
+    DROP COLUMN IF EXISTS website,
+    DROP COLUMN IF EXISTS metacritic_url,
+    DROP COLUMN IF EXISTS metacritic_score,
+    DROP COLUMN IF EXISTS required_age,
+    DROP COLUMN IF EXISTS coming_soon;
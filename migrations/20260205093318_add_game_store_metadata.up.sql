@@ -0,0 +1,51 @@
+-- Add up migration script here
+ALTER TABLE games
+    ADD COLUMN IF NOT EXISTS coming_soon BOOLEAN NOT NULL DEFAULT false,
+    ADD COLUMN IF NOT EXISTS required_age INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS metacritic_score INTEGER,
+    ADD COLUMN IF NOT EXISTS metacritic_url TEXT,
+    ADD COLUMN IF NOT EXISTS website TEXT,
+    ADD COLUMN IF NOT EXISTS supported_languages TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN IF NOT EXISTS background TEXT;
+
+CREATE TABLE IF NOT EXISTS developers (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name VARCHAR(255) NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS game_developers (
+    game_id UUID NOT NULL REFERENCES games(id) ON DELETE CASCADE,
+    developer_id UUID NOT NULL REFERENCES developers(id) ON DELETE CASCADE,
+    PRIMARY KEY (game_id, developer_id)
+);
+
+CREATE TABLE IF NOT EXISTS publishers (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name VARCHAR(255) NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS game_publishers (
+    game_id UUID NOT NULL REFERENCES games(id) ON DELETE CASCADE,
+    publisher_id UUID NOT NULL REFERENCES publishers(id) ON DELETE CASCADE,
+    PRIMARY KEY (game_id, publisher_id)
+);
+
+CREATE TABLE IF NOT EXISTS game_movies (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    game_id UUID NOT NULL REFERENCES games(id) ON DELETE CASCADE,
+    steam_movie_id INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    thumbnail TEXT NOT NULL,
+    webm_480 TEXT,
+    webm_max TEXT,
+    mp4_480 TEXT,
+    mp4_max TEXT,
+    highlight BOOLEAN NOT NULL DEFAULT false,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (game_id, steam_movie_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_game_developers_developer_id ON game_developers(developer_id);
+CREATE INDEX IF NOT EXISTS idx_game_publishers_publisher_id ON game_publishers(publisher_id);
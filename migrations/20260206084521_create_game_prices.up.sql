@@ -0,0 +1,17 @@
+-- Add up migration script here
+-- One row per game, region and day. Prices are in the smallest currency unit.
+CREATE TABLE IF NOT EXISTS game_prices (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    game_id UUID NOT NULL REFERENCES games(id) ON DELETE CASCADE,
+    country_code VARCHAR(2) NOT NULL,
+    currency VARCHAR(10) NOT NULL,
+    initial_price INTEGER NOT NULL,
+    final_price INTEGER NOT NULL,
+    discount_percent INTEGER NOT NULL DEFAULT 0,
+    snapshot_date DATE NOT NULL DEFAULT CURRENT_DATE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(game_id, country_code, snapshot_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_game_prices_region_final ON game_prices(game_id, country_code, final_price);
@@ -0,0 +1,36 @@
+-- Add up migration script here
+-- Each entry belongs to either a tracked user or an admin
+CREATE TABLE IF NOT EXISTS watchlists (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE,
+    admin_id UUID REFERENCES admins(id) ON DELETE CASCADE,
+    game_id UUID NOT NULL REFERENCES games(id) ON DELETE CASCADE,
+    country_code VARCHAR(2) NOT NULL,
+    target_price INTEGER,
+    discount_threshold INTEGER,
+    -- Price of the last alert, cleared once the game stops matching so the next sale alerts again
+    last_alerted_price INTEGER,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((user_id IS NULL) <> (admin_id IS NULL)),
+    CHECK (target_price IS NOT NULL OR discount_threshold IS NOT NULL)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_watchlists_user_game_region
+    ON watchlists(user_id, game_id, country_code) WHERE user_id IS NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_watchlists_admin_game_region
+    ON watchlists(admin_id, game_id, country_code) WHERE admin_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_watchlists_game_id ON watchlists(game_id);
+
+CREATE TABLE IF NOT EXISTS price_alerts (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    watchlist_id UUID NOT NULL REFERENCES watchlists(id) ON DELETE CASCADE,
+    currency VARCHAR(10) NOT NULL,
+    initial_price INTEGER NOT NULL,
+    final_price INTEGER NOT NULL,
+    discount_percent INTEGER NOT NULL,
+    webhook_status VARCHAR(16) NOT NULL DEFAULT 'pending',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_price_alerts_watchlist_created ON price_alerts(watchlist_id, created_at);
@@ -0,0 +1,9 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS game_changes;
+DROP INDEX IF EXISTS idx_games_sync_attempted_at;
+
+ALTER TABLE games
+    DROP COLUMN IF EXISTS sync_error,
+    DROP COLUMN IF EXISTS sync_status,
+    DROP COLUMN IF EXISTS sync_attempted_at,
+    DROP COLUMN IF EXISTS last_synced_at;
@@ -0,0 +1,23 @@
+-- Add up migration script here
+ALTER TABLE games
+    ADD COLUMN IF NOT EXISTS last_synced_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS sync_attempted_at TIMESTAMPTZ,
+    -- pending (never synced, e.g. library stubs), synced, failed or not_found (no longer on the store)
+    ADD COLUMN IF NOT EXISTS sync_status VARCHAR(16) NOT NULL DEFAULT 'pending',
+    ADD COLUMN IF NOT EXISTS sync_error TEXT;
+
+-- Existing games stay pending with no last_synced_at, they predate the metadata tables, so
+-- their first sync fills them in without recording every field as a change
+
+CREATE INDEX IF NOT EXISTS idx_games_sync_attempted_at ON games(sync_attempted_at NULLS FIRST);
+
+CREATE TABLE IF NOT EXISTS game_changes (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    game_id UUID NOT NULL REFERENCES games(id) ON DELETE CASCADE,
+    field VARCHAR(64) NOT NULL,
+    old_value JSONB,
+    new_value JSONB,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_game_changes_game_created ON game_changes(game_id, created_at);
@@ -0,0 +1,5 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS game_relations;
+DROP INDEX IF EXISTS idx_games_app_type;
+
+ALTER TABLE games DROP COLUMN IF EXISTS app_type;
@@ -0,0 +1,21 @@
+-- Add up migration script here
+-- appdetails `type`: game, dlc, demo, music, video, mod, ...
+ALTER TABLE games ADD COLUMN IF NOT EXISTS app_type VARCHAR(32) NOT NULL DEFAULT 'game';
+
+-- Existing rows only got the default, so they are queued to be synced again before their type
+-- is trusted. Like a first sync, filling them in is not recorded as changes.
+UPDATE games SET sync_status = 'pending', sync_attempted_at = NULL, last_synced_at = NULL
+WHERE sync_status <> 'not_found';
+
+CREATE INDEX IF NOT EXISTS idx_games_app_type ON games(app_type);
+
+-- Children (DLC, demos, soundtracks) are linked by appid, so either side may not be stored yet.
+-- Rows come from the parent's `dlc` and `demos` lists and from the child's `fullgame`.
+CREATE TABLE IF NOT EXISTS game_relations (
+    parent_appid VARCHAR NOT NULL,
+    child_appid VARCHAR NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (parent_appid, child_appid)
+);
+
+CREATE INDEX IF NOT EXISTS idx_game_relations_child ON game_relations(child_appid);
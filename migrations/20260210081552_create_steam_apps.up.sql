@@ -0,0 +1,11 @@
+-- Add up migration script here
+-- Every app known to ISteamApps/GetAppList, used to look games up by name
+CREATE TABLE IF NOT EXISTS steam_apps (
+    appid VARCHAR PRIMARY KEY,
+    name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_steam_apps_name_trgm ON steam_apps USING GIN (name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_steam_apps_name_lower ON steam_apps(LOWER(name));
@@ -0,0 +1,20 @@
+-- Add up migration script here
+-- Articles from ISteamNews/GetNewsForApp, `gid` is Steam's id for the article
+CREATE TABLE IF NOT EXISTS game_news (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    game_id UUID NOT NULL REFERENCES games(id) ON DELETE CASCADE,
+    gid VARCHAR NOT NULL UNIQUE,
+    title TEXT NOT NULL,
+    url TEXT NOT NULL,
+    author TEXT,
+    contents TEXT NOT NULL,
+    feed_label TEXT NOT NULL,
+    feed_name VARCHAR NOT NULL,
+    is_external_url BOOLEAN NOT NULL DEFAULT false,
+    published_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_game_news_game_published ON game_news(game_id, published_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_game_news_published ON game_news(published_at DESC, id DESC);
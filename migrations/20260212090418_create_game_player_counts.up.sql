@@ -0,0 +1,10 @@
+-- Add up migration script here
+-- Samples of ISteamUserStats/GetNumberOfCurrentPlayers
+CREATE TABLE IF NOT EXISTS game_player_counts (
+    game_id UUID NOT NULL REFERENCES games(id) ON DELETE CASCADE,
+    player_count INTEGER NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (game_id, recorded_at)
+);
+
+CREATE INDEX IF NOT EXISTS idx_game_player_counts_recorded_at ON game_player_counts(recorded_at);
@@ -0,0 +1,9 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS game_reviews;
+DROP INDEX IF EXISTS idx_games_review_score;
+
+ALTER TABLE games
+    DROP COLUMN IF EXISTS review_total_negative,
+    DROP COLUMN IF EXISTS review_total_positive,
+    DROP COLUMN IF EXISTS review_score_desc,
+    DROP COLUMN IF EXISTS review_score;
@@ -0,0 +1,25 @@
+-- Add up migration script here
+-- Latest appreviews summary, `review_score` is Steam's rating from 0 (no user reviews)
+-- to 9 (overwhelmingly positive) with `review_score_desc` as its label
+ALTER TABLE games
+    ADD COLUMN IF NOT EXISTS review_score INTEGER,
+    ADD COLUMN IF NOT EXISTS review_score_desc VARCHAR(64),
+    ADD COLUMN IF NOT EXISTS review_total_positive INTEGER,
+    ADD COLUMN IF NOT EXISTS review_total_negative INTEGER;
+
+CREATE INDEX IF NOT EXISTS idx_games_review_score ON games(review_score);
+
+-- One summary per game and day
+CREATE TABLE IF NOT EXISTS game_reviews (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    game_id UUID NOT NULL REFERENCES games(id) ON DELETE CASCADE,
+    review_score INTEGER NOT NULL,
+    review_score_desc VARCHAR(64) NOT NULL,
+    total_positive INTEGER NOT NULL,
+    total_negative INTEGER NOT NULL,
+    total_reviews INTEGER NOT NULL,
+    snapshot_date DATE NOT NULL DEFAULT CURRENT_DATE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(game_id, snapshot_date)
+);
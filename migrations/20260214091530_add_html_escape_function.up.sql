@@ -0,0 +1,8 @@
+-- Add up migration script here
+-- Search highlights wrap matches in <mark> tags, the Steam and user provided text
+-- around them is escaped first
+CREATE OR REPLACE FUNCTION html_escape(value TEXT) RETURNS TEXT
+LANGUAGE SQL IMMUTABLE STRICT PARALLEL SAFE AS $$
+    SELECT replace(replace(replace(replace(replace(value,
+        '&', '&amp;'), '<', '&lt;'), '>', '&gt;'), '"', '&quot;'), '''', '&#39;')
+$$;
@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_games_prices_imported_at;
+
+ALTER TABLE games DROP COLUMN IF EXISTS prices_imported_at;
@@ -0,0 +1,5 @@
+-- Add up migration script here
+-- Last price import attempt, the price job picks the games that are due in batches
+ALTER TABLE games ADD COLUMN IF NOT EXISTS prices_imported_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_games_prices_imported_at ON games(prices_imported_at NULLS FIRST);
@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use actix_web::rt;
+use sqlx::PgPool;
+
+use crate::{repositories::game_repository::GameRepository, services::news_service::NewsService};
+
+const NEWS_INTERVAL: Duration = Duration::from_secs(60 * 60 * 6);
+const GAME_DELAY: Duration = Duration::from_secs(1);
+
+// Pulls the latest articles of every stored game, known articles are only updated
+pub fn spawn(pool: PgPool) {
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(NEWS_INTERVAL);
+        loop {
+            interval.tick().await;
+            run(&pool).await;
+        }
+    });
+}
+
+async fn run(pool: &PgPool) {
+    let appids = match GameRepository::get_all_appids(pool).await {
+        Ok(appids) => appids,
+        Err(e) => {
+            eprintln!("Game news job failed to load games: {:?}", e);
+            return;
+        }
+    };
+
+    for appid in appids {
+        if let Err(e) = NewsService::import_game_news(pool, &appid).await {
+            eprintln!("News import failed for {}: {:?}", appid, e);
+        }
+        rt::time::sleep(GAME_DELAY).await;
+    }
+}
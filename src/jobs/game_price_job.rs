@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use actix_web::rt;
+use sqlx::PgPool;
+
+use crate::{
+    models::game::constants::{GAME_PRICE_BATCH_SIZE, GAME_PRICE_IMPORT_AFTER_HOURS},
+    repositories::game_repository::GameRepository,
+    services::game_price_service::GamePriceService,
+};
+
+const PRICE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+// appdetails is rate limited, so games are imported one at a time with a pause in between
+const GAME_DELAY: Duration = Duration::from_secs(2);
+
+// Records the day's price in every tracked region for a batch of the games that are due
+// every hour, a batch ends well before the next run
+pub fn spawn(pool: PgPool) {
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(PRICE_INTERVAL);
+        loop {
+            interval.tick().await;
+            run(&pool).await;
+        }
+    });
+}
+
+async fn run(pool: &PgPool) {
+    let appids = match GameRepository::get_price_stale_appids(
+        pool,
+        GAME_PRICE_IMPORT_AFTER_HOURS,
+        GAME_PRICE_BATCH_SIZE,
+    )
+    .await
+    {
+        Ok(appids) => appids,
+        Err(e) => {
+            eprintln!("Game price job failed to load games: {:?}", e);
+            return;
+        }
+    };
+
+    for appid in appids {
+        if let Err(e) = GamePriceService::import_game_prices(pool, &appid).await {
+            eprintln!("Price import failed for {}: {:?}", appid, e);
+        }
+        rt::time::sleep(GAME_DELAY).await;
+    }
+}
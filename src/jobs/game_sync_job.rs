@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use actix_web::rt;
+use sqlx::PgPool;
+
+use crate::{
+    models::game::constants::{GAME_RESYNC_AFTER_DAYS, GAME_RESYNC_BATCH_SIZE},
+    repositories::game_repository::GameRepository,
+    services::game_service::GameService,
+};
+
+const SYNC_INTERVAL: Duration = Duration::from_secs(60 * 60);
+// appdetails is rate limited, so games are synced one at a time with a pause in between
+const GAME_DELAY: Duration = Duration::from_secs(2);
+
+// Resyncs a batch of the stalest games every hour, failed games are retried once they are
+// stale again
+pub fn spawn(pool: PgPool) {
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(SYNC_INTERVAL);
+        loop {
+            interval.tick().await;
+            run(&pool).await;
+        }
+    });
+}
+
+async fn run(pool: &PgPool) {
+    let appids = match GameRepository::get_stale_appids(
+        pool,
+        GAME_RESYNC_AFTER_DAYS,
+        GAME_RESYNC_BATCH_SIZE,
+    )
+    .await
+    {
+        Ok(appids) => appids,
+        Err(e) => {
+            eprintln!("Game sync job failed to load games: {:?}", e);
+            return;
+        }
+    };
+
+    for appid in appids {
+        if let Err(e) = GameService::resync_game(pool, &appid).await {
+            eprintln!("Game sync failed for {}: {:?}", appid, e);
+        }
+        rt::time::sleep(GAME_DELAY).await;
+    }
+}
@@ -0,0 +1,19 @@
+pub mod game_news_job;
+pub mod game_price_job;
+pub mod game_sync_job;
+pub mod player_count_job;
+pub mod playtime_snapshot_job;
+pub mod steam_app_job;
+pub mod user_purge_job;
+
+use sqlx::PgPool;
+
+pub fn spawn_jobs(pool: PgPool) {
+    playtime_snapshot_job::spawn(pool.clone());
+    game_price_job::spawn(pool.clone());
+    game_sync_job::spawn(pool.clone());
+    game_news_job::spawn(pool.clone());
+    player_count_job::spawn(pool.clone());
+    steam_app_job::spawn(pool.clone());
+    user_purge_job::spawn(pool);
+}
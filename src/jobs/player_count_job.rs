@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use actix_web::rt;
+use sqlx::PgPool;
+
+use crate::{
+    repositories::player_count_repository::PlayerCountRepository,
+    services::player_count_service::PlayerCountService,
+};
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const GAME_DELAY: Duration = Duration::from_secs(1);
+
+// Records the current players of every stored game once an hour
+pub fn spawn(pool: PgPool) {
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(SAMPLE_INTERVAL);
+        loop {
+            interval.tick().await;
+            run(&pool).await;
+        }
+    });
+}
+
+async fn run(pool: &PgPool) {
+    let appids = match PlayerCountRepository::get_tracked_appids(pool).await {
+        Ok(appids) => appids,
+        Err(e) => {
+            eprintln!("Player count job failed to load games: {:?}", e);
+            return;
+        }
+    };
+
+    for appid in appids {
+        if let Err(e) = PlayerCountService::import_player_count(pool, &appid).await {
+            eprintln!("Player count import failed for {}: {:?}", appid, e);
+        }
+        rt::time::sleep(GAME_DELAY).await;
+    }
+}
@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use actix_web::rt;
+use sqlx::PgPool;
+
+use crate::{
+    repositories::user_repository::UserRepository, services::user_games_service::UserGamesService,
+};
+
+const SNAPSHOT_INTERVAL: Duration = Duration::from_secs(60 * 60 * 24);
+
+// Imports every user's recently played games once a day, which also records
+// that day's playtime snapshot for each of them
+pub fn spawn(pool: PgPool) {
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(SNAPSHOT_INTERVAL);
+        loop {
+            interval.tick().await;
+            run(&pool).await;
+        }
+    });
+}
+
+async fn run(pool: &PgPool) {
+    let steam_ids = match UserRepository::get_all_steam_ids(pool).await {
+        Ok(steam_ids) => steam_ids,
+        Err(e) => {
+            eprintln!("Playtime snapshot job failed to load users: {:?}", e);
+            return;
+        }
+    };
+
+    for steam_id in steam_ids {
+        if let Err(e) = UserGamesService::import_recently_played_games(pool, &steam_id).await {
+            eprintln!("Playtime snapshot failed for {}: {:?}", steam_id, e);
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+use actix_web::rt;
+use sqlx::PgPool;
+
+use crate::services::steam_app_service::SteamAppService;
+
+const IMPORT_INTERVAL: Duration = Duration::from_secs(60 * 60 * 24);
+
+// Keeps the local copy of GetAppList current for name lookups
+pub fn spawn(pool: PgPool) {
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(IMPORT_INTERVAL);
+        loop {
+            interval.tick().await;
+            run(&pool).await;
+        }
+    });
+}
+
+async fn run(pool: &PgPool) {
+    match SteamAppService::import_steam_apps(pool).await {
+        Ok(response) if response.updated > 0 => {
+            println!("Imported {} new or renamed Steam apps", response.updated)
+        }
+        Ok(_) => {}
+        Err(e) => eprintln!("Steam app import failed: {:?}", e),
+    }
+}
@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use actix_web::rt;
+use sqlx::PgPool;
+
+use crate::{
+    models::user::constants::DELETED_USER_RETENTION_DAYS,
+    repositories::user_repository::UserRepository,
+};
+
+const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60 * 24);
+
+// Hard deletes users that were soft deleted longer than the retention period ago,
+// along with everything that cascades from them (inventories, items, games, ...)
+pub fn spawn(pool: PgPool) {
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(PURGE_INTERVAL);
+        loop {
+            interval.tick().await;
+            run(&pool).await;
+        }
+    });
+}
+
+async fn run(pool: &PgPool) {
+    match UserRepository::purge_deleted_users(pool, DELETED_USER_RETENTION_DAYS).await {
+        Ok(0) => {}
+        Ok(rows) => println!("Purged {} deleted users", rows),
+        Err(e) => eprintln!("User purge job failed: {:?}", e),
+    }
+}
@@ -1,3 +1,4 @@
+mod jobs;
 mod middleware;
 mod models;
 mod repositories;
@@ -41,6 +42,8 @@ async fn main() -> std::io::Result<()> {
         }
     };
 
+    jobs::spawn_jobs(pool.clone());
+
     println!("Server started!");
 
     HttpServer::new(move || {
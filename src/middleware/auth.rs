@@ -1,9 +1,17 @@
-use actix_web::{Error, HttpMessage, dev::ServiceRequest};
+use std::future::{Ready, ready};
+
+use actix_web::{
+    Error, FromRequest, HttpMessage, HttpRequest,
+    dev::{Payload, ServiceRequest},
+    http::header,
+};
 use actix_web_httpauth::extractors::bearer::BearerAuth;
 use chrono::{Duration, Utc};
 use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, encode};
 use serde::{Deserialize, Serialize};
 
+use crate::models::user::viewer::Viewer;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Claims {
     pub sub: String,
@@ -76,3 +84,37 @@ pub async fn validator(
         }
     }
 }
+
+// Public routes stay open, a valid admin access token only unlocks details of private profiles
+impl FromRequest for Viewer {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        // Without a secret no token can be verified
+        let Ok(jwt_secret) = std::env::var("JWT_SECRET") else {
+            return ready(Ok(Viewer::Public));
+        };
+
+        let is_admin = req
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .and_then(|token| {
+                decode::<Claims>(
+                    token,
+                    &DecodingKey::from_secret(jwt_secret.as_bytes()),
+                    &Validation::new(Algorithm::HS256),
+                )
+                .ok()
+            })
+            .is_some_and(|token_data| token_data.claims.token_type == "access");
+
+        ready(Ok(if is_admin {
+            Viewer::Admin
+        } else {
+            Viewer::Public
+        }))
+    }
+}
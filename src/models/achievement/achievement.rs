@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, FromRow, Deserialize, Serialize)]
+#[allow(non_snake_case)]
+pub struct AchievementModel {
+    pub id: Uuid,
+    pub game_id: Uuid,
+    pub api_name: String,
+    pub display_name: String,
+    pub description: Option<String>,
+    pub icon: String,
+    pub icon_gray: String,
+    pub hidden: bool,
+    pub global_percent: Option<f64>,
+    pub global_percent_updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(rename = "createdAt")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
@@ -0,0 +1,6 @@
+pub const MIN_RECENT_ACHIEVEMENTS_LIMIT: i64 = 1;
+pub const MAX_RECENT_ACHIEVEMENTS_LIMIT: i64 = 100;
+pub const DEFAULT_RECENT_ACHIEVEMENTS_LIMIT: i64 = 20;
+
+// Achievements unlocked by less than this share of all players are flagged as rare
+pub const RARE_ACHIEVEMENT_PERCENT: f64 = 10.0;
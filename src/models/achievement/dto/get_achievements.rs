@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{ResponseStatus, achievement::constants::DEFAULT_RECENT_ACHIEVEMENTS_LIMIT};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GameCompletion {
+    pub appid: String,
+    pub name: String,
+    pub unlocked: i64,
+    pub total: i64,
+    pub completion_percentage: f64,
+    pub rarity_score: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetAchievementCompletionResponse {
+    pub status: ResponseStatus,
+    pub total: usize,
+    pub rarity_score: f64,
+    pub games: Vec<GameCompletion>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecentAchievement {
+    pub appid: String,
+    pub game_name: String,
+    pub api_name: String,
+    pub display_name: String,
+    pub description: Option<String>,
+    pub icon: String,
+    pub global_percent: Option<f64>,
+    pub rare: bool,
+    pub unlocked_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RecentAchievementsQueryParams {
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+}
+
+fn default_limit() -> i64 {
+    DEFAULT_RECENT_ACHIEVEMENTS_LIMIT
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetRecentAchievementsResponse {
+    pub status: ResponseStatus,
+    pub achievements: Vec<RecentAchievement>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GameAchievementStats {
+    pub api_name: String,
+    pub display_name: String,
+    pub description: Option<String>,
+    pub icon: String,
+    pub hidden: bool,
+    pub global_percent: Option<f64>,
+    pub rare: bool,
+    pub tracked_unlocks: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetGameAchievementsResponse {
+    pub status: ResponseStatus,
+    pub appid: String,
+    pub total: usize,
+    pub achievements: Vec<GameAchievementStats>,
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::ResponseStatus;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpsertAchievementSchema {
+    pub game_id: Uuid,
+    pub api_name: String,
+    pub display_name: String,
+    pub description: Option<String>,
+    pub icon: String,
+    pub icon_gray: String,
+    pub hidden: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpsertUserAchievementSchema {
+    pub user_id: Uuid,
+    pub achievement_id: Uuid,
+    pub unlocked_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ImportAchievementsResponse {
+    pub status: ResponseStatus,
+    pub imported: usize,
+}
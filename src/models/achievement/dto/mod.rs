@@ -0,0 +1,11 @@
+pub mod get_achievements;
+pub mod import_achievements;
+
+pub use get_achievements::{
+    GameAchievementStats, GameCompletion, GetAchievementCompletionResponse,
+    GetGameAchievementsResponse, GetRecentAchievementsResponse, RecentAchievement,
+    RecentAchievementsQueryParams,
+};
+pub use import_achievements::{
+    ImportAchievementsResponse, UpsertAchievementSchema, UpsertUserAchievementSchema,
+};
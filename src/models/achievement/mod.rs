@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)]
+pub mod achievement;
+pub mod constants;
+pub mod dto;
+pub mod user_achievement;
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, FromRow, Deserialize, Serialize)]
+#[allow(non_snake_case)]
+pub struct UserAchievementModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub achievement_id: Uuid,
+    pub unlocked_at: chrono::DateTime<chrono::Utc>,
+    #[serde(rename = "createdAt")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, FromRow, Deserialize, Serialize)]
+#[allow(non_snake_case)]
+pub struct UserBanModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub vac_banned: bool,
+    pub number_of_vac_bans: i32,
+    pub days_since_last_ban: i32,
+    pub number_of_game_bans: i32,
+    pub community_banned: bool,
+    pub economy_ban: String,
+    pub is_banned: bool,
+    #[serde(rename = "createdAt")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::ResponseStatus;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BanStatus {
+    pub vac_banned: bool,
+    pub number_of_vac_bans: i32,
+    pub days_since_last_ban: i32,
+    pub number_of_game_bans: i32,
+    pub community_banned: bool,
+    pub economy_ban: String,
+    pub is_banned: bool,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BanHistoryEntry {
+    pub vac_banned: bool,
+    pub number_of_vac_bans: i32,
+    pub days_since_last_ban: i32,
+    pub number_of_game_bans: i32,
+    pub community_banned: bool,
+    pub economy_ban: String,
+    pub recorded_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetUserBansResponse {
+    pub status: ResponseStatus,
+    pub bans: Option<BanStatus>,
+    pub history: Vec<BanHistoryEntry>,
+}
@@ -0,0 +1,5 @@
+pub mod get_bans;
+pub mod upsert_bans;
+
+pub use get_bans::{BanHistoryEntry, BanStatus, GetUserBansResponse};
+pub use upsert_bans::UpsertUserBanSchema;
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UpsertUserBanSchema {
+    pub user_id: Uuid,
+    pub vac_banned: bool,
+    pub number_of_vac_bans: i32,
+    pub days_since_last_ban: i32,
+    pub number_of_game_bans: i32,
+    pub community_banned: bool,
+    pub economy_ban: String,
+}
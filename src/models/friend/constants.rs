@@ -0,0 +1,3 @@
+// Registering a user costs several Steam calls, so an import registers at most this
+// many untracked friends. The rest are picked up by the next import.
+pub const MAX_FRIEND_REGISTRATIONS_PER_IMPORT: usize = 5;
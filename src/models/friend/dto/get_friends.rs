@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::ResponseStatus;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TrackedFriend {
+    pub steam_id: String,
+    pub username: String,
+    pub avatar: String,
+    pub pf_url: String,
+    pub current_game: Option<String>,
+    pub gameid: Option<String>,
+    pub friend_since: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MutualFriend {
+    pub steam_id: String,
+    pub username: Option<String>,
+    pub avatar: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetFriendsResponse {
+    pub status: ResponseStatus,
+    pub total: usize,
+    pub friends: Vec<TrackedFriend>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetMutualFriendsResponse {
+    pub status: ResponseStatus,
+    pub total: usize,
+    pub friends: Vec<MutualFriend>,
+}
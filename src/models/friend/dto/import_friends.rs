@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::ResponseStatus;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpsertFriendSchema {
+    pub user_id: Uuid,
+    pub friend_steam_id: String,
+    pub relationship: String,
+    pub friend_since: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ImportFriendsQueryParams {
+    // Registers friends that are not tracked yet through the regular user creation
+    #[serde(default)]
+    pub register: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ImportFriendsResponse {
+    pub status: ResponseStatus,
+    pub imported: usize,
+    pub registered: usize,
+    // Untracked friends left for a later import once the registration cap was reached
+    pub pending_registrations: usize,
+}
@@ -0,0 +1,5 @@
+pub mod get_friends;
+pub mod import_friends;
+
+pub use get_friends::{GetFriendsResponse, GetMutualFriendsResponse, MutualFriend, TrackedFriend};
+pub use import_friends::{ImportFriendsQueryParams, ImportFriendsResponse, UpsertFriendSchema};
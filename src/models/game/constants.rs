@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+// Regions (appdetails `cc`) whose prices are recorded on every price sync. The
+// first one the game is sold in also feeds the price stored on the game.
+pub const TRACKED_PRICE_REGIONS: [&str; 5] = ["US", "GB", "DE", "BR", "JP"];
+// Pause between the appdetails calls of one game's regions
+pub const PRICE_REGION_DELAY: Duration = Duration::from_secs(2);
+// Prices of synced and watchlisted games are imported again once their last import is this old
+pub const GAME_PRICE_IMPORT_AFTER_HOURS: i32 = 24;
+pub const GAME_PRICE_BATCH_SIZE: i64 = 100;
+
+// Stored games are refreshed from appdetails once their last sync attempt is this old
+pub const GAME_RESYNC_AFTER_DAYS: i32 = 7;
+pub const GAME_RESYNC_BATCH_SIZE: i64 = 100;
+
+// Player count charts cover this many days when no range is given
+pub const PLAYER_COUNT_DEFAULT_DAYS: i64 = 7;
+// Trending compares the average players of the last window with the one before it
+pub const TRENDING_DEFAULT_HOURS: i32 = 24;
+pub const TRENDING_MAX_HOURS: i32 = 168;
@@ -1,15 +1,32 @@
 use serde::{Deserialize, Serialize};
 
+// Either the appid or the exact store name, the appid wins when both are set
 #[derive(Serialize, Deserialize, Debug)]
 pub struct CreateGameRequest {
-    pub appid: String,
+    pub appid: Option<String>,
+    pub name: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct CreateGameSchema {
     pub appid: String,
+    pub app_type: String,
     pub name: String,
     pub short_description: String,
     pub header_image: String,
     pub screenshots: Vec<String>,
+    pub is_free: bool,
+    pub price_final: Option<i32>,
+    pub price_currency: Option<String>,
+    pub release_date: Option<chrono::NaiveDate>,
+    pub platform_windows: bool,
+    pub platform_mac: bool,
+    pub platform_linux: bool,
+    pub coming_soon: bool,
+    pub required_age: i32,
+    pub metacritic_score: Option<i32>,
+    pub metacritic_url: Option<String>,
+    pub website: Option<String>,
+    pub supported_languages: Vec<String>,
+    pub background: Option<String>,
 }
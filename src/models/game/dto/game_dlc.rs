@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::models::ResponseStatus;
+
+#[derive(Deserialize, Debug)]
+pub struct GameDlcQueryParams {
+    // appdetails type of the children, e.g. `dlc`, `demo`, `music`
+    #[serde(rename = "type")]
+    pub app_type: Option<String>,
+}
+
+// Children that are not stored yet only carry their appid
+#[derive(Serialize, Deserialize, Debug, FromRow)]
+pub struct GameDlc {
+    pub appid: String,
+    #[serde(rename = "type")]
+    pub app_type: Option<String>,
+    pub name: Option<String>,
+    pub header_image: Option<String>,
+    pub is_free: Option<bool>,
+    pub price_final: Option<i32>,
+    pub price_currency: Option<String>,
+    pub release_date: Option<chrono::NaiveDate>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetGameDlcResponse {
+    pub status: ResponseStatus,
+    pub appid: String,
+    pub total: usize,
+    pub dlc: Vec<GameDlc>,
+}
@@ -0,0 +1,92 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::models::{
+    ResponseStatus,
+    game::pagination::game_filters::{GameFilters, GamePlatform, GameSortField},
+    pagination::{SortOrder, constants::DEFAULT_PAGE_SIZE},
+    user::dto::get_users::PaginationMeta,
+};
+
+#[derive(Deserialize, Debug)]
+pub struct GamesQueryParams {
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    pub cursor: Option<String>,
+    #[serde(default)]
+    pub include_total: bool,
+    // Case insensitive substring of the game name
+    pub name: Option<String>,
+    // Genre or category description, e.g. `Action`, `Multi-player`
+    pub genre: Option<String>,
+    pub category: Option<String>,
+    pub platform: Option<GamePlatform>,
+    // appdetails type, e.g. `game`, `dlc`, `demo`, `music`
+    #[serde(rename = "type")]
+    pub app_type: Option<String>,
+    pub min_price: Option<i32>,
+    pub max_price: Option<i32>,
+    pub free: Option<bool>,
+    pub release_year: Option<i32>,
+    pub min_owners: Option<i64>,
+    pub min_players: Option<i64>,
+    pub min_review_score: Option<i32>,
+    #[serde(default)]
+    pub sort: GameSortField,
+    #[serde(default)]
+    pub order: SortOrder,
+}
+
+impl GamesQueryParams {
+    pub fn filters(&self) -> GameFilters {
+        GameFilters {
+            name: self.name.clone(),
+            genre: self.genre.clone(),
+            category: self.category.clone(),
+            platform: self.platform,
+            app_type: self.app_type.clone(),
+            min_price: self.min_price,
+            max_price: self.max_price,
+            free: self.free,
+            release_year: self.release_year,
+            min_owners: self.min_owners,
+            min_players: self.min_players,
+            min_review_score: self.min_review_score,
+        }
+    }
+}
+
+fn default_limit() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
+
+#[derive(Serialize, Deserialize, Debug, FromRow)]
+pub struct CatalogGame {
+    pub appid: String,
+    #[serde(rename = "type")]
+    pub app_type: String,
+    pub name: String,
+    pub short_description: Option<String>,
+    pub header_image: Option<String>,
+    pub is_free: bool,
+    pub price_final: Option<i32>,
+    pub price_currency: Option<String>,
+    pub release_date: Option<NaiveDate>,
+    pub platforms: Vec<String>,
+    pub genres: Vec<String>,
+    pub categories: Vec<String>,
+    pub owners: i64,
+    pub players: i64,
+    pub review_score: Option<i32>,
+    pub review_score_desc: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct GetGamesResponse {
+    pub status: ResponseStatus,
+    pub games: Vec<CatalogGame>,
+    pub pagination: PaginationMeta,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+}
@@ -6,3 +6,43 @@ pub use bind_user_to_game::BindUserToGameSchema;
 
 pub mod check_if_user_already_has_game;
 // pub use check_if_user_already_has_game::CheckIfUserAlreadyHasGame;
+
+pub mod owned_games;
+pub use owned_games::{
+    CreateGameStubSchema, GetUserGamesResponse, ImportOwnedGamesResponse, UpsertUserGameSchema,
+    UserGame, UserGamesQueryParams, UserGamesSort,
+};
+
+pub mod playtime;
+pub use playtime::{
+    GamePlaytime, GetPlaytimeResponse, PlaytimeQueryParams, UpsertPlaytimeSnapshotSchema,
+};
+
+pub mod get_games;
+pub use get_games::{CatalogGame, GamesQueryParams, GetGamesResponse};
+
+pub mod prices;
+pub use prices::{
+    CurrentPrice, GetCurrentPricesResponse, GetLowestPricesResponse, GetPriceHistoryResponse,
+    ImportGamePricesResponse, LowestPrice, PriceHistoryQueryParams, PriceQueryParams,
+    PriceSnapshot, UpsertGamePriceSchema,
+};
+
+pub mod resync_game;
+pub use resync_game::{GameChangesQueryParams, GetGameChangesResponse, ResyncGameResponse};
+
+pub mod game_dlc;
+pub use game_dlc::{GameDlc, GameDlcQueryParams, GetGameDlcResponse};
+
+pub mod player_counts;
+pub use player_counts::{
+    GetPlayerCountsResponse, GetTrendingGamesResponse, ImportPlayerCountResponse,
+    PlayerCountBucket, PlayerCountPoint, PlayerCountsQueryParams, TrendingGame,
+    TrendingQueryParams,
+};
+
+pub mod reviews;
+pub use reviews::{
+    GetReviewHistoryResponse, ImportGameReviewsResponse, ReviewHistoryQueryParams,
+    ReviewSnapshot, UpsertGameReviewSchema,
+};
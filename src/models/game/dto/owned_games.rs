@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::ResponseStatus;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateGameStubSchema {
+    pub appid: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpsertUserGameSchema {
+    pub user_id: Uuid,
+    pub game_id: Uuid,
+    pub playtime_forever: i32,
+    pub playtime_2weeks: i32,
+    pub last_played_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum UserGamesSort {
+    #[default]
+    Playtime,
+    RecentPlaytime,
+    LastPlayed,
+    Name,
+}
+
+impl UserGamesSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserGamesSort::Playtime => "playtime",
+            UserGamesSort::RecentPlaytime => "recent_playtime",
+            UserGamesSort::LastPlayed => "last_played",
+            UserGamesSort::Name => "name",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UserGamesQueryParams {
+    #[serde(default)]
+    pub sort: UserGamesSort,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserGame {
+    pub appid: String,
+    pub name: String,
+    pub header_image: Option<String>,
+    pub playtime_forever: i32,
+    pub playtime_2weeks: i32,
+    pub last_played_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetUserGamesResponse {
+    pub status: ResponseStatus,
+    pub total: usize,
+    pub games: Vec<UserGame>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ImportOwnedGamesResponse {
+    pub status: ResponseStatus,
+    pub imported: usize,
+}
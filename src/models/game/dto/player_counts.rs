@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::models::{
+    ResponseStatus, game::constants::TRENDING_DEFAULT_HOURS,
+    pagination::constants::DEFAULT_PAGE_SIZE,
+};
+
+#[derive(Deserialize, Debug, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PlayerCountBucket {
+    #[default]
+    Hour,
+    Day,
+}
+
+impl PlayerCountBucket {
+    // Passed to date_trunc
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlayerCountBucket::Hour => "hour",
+            PlayerCountBucket::Day => "day",
+        }
+    }
+}
+
+// `from` and `to` are inclusive days, by default the last week
+#[derive(Deserialize, Debug)]
+pub struct PlayerCountsQueryParams {
+    pub from: Option<chrono::NaiveDate>,
+    pub to: Option<chrono::NaiveDate>,
+    #[serde(default)]
+    pub bucket: PlayerCountBucket,
+}
+
+#[derive(Serialize, Deserialize, Debug, FromRow)]
+pub struct PlayerCountPoint {
+    pub bucket_start: chrono::DateTime<chrono::Utc>,
+    pub min: i32,
+    pub max: i32,
+    pub avg: f64,
+    pub samples: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetPlayerCountsResponse {
+    pub status: ResponseStatus,
+    pub appid: String,
+    pub bucket: String,
+    pub from: chrono::NaiveDate,
+    pub to: chrono::NaiveDate,
+    pub points: Vec<PlayerCountPoint>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ImportPlayerCountResponse {
+    pub status: ResponseStatus,
+    pub appid: String,
+    // `None` when Steam has no count for the app, nothing is recorded then
+    pub player_count: Option<i32>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TrendingQueryParams {
+    #[serde(default = "default_hours")]
+    pub hours: i32,
+    // Ignores games whose current average is below this, tiny player bases swing too much
+    #[serde(default)]
+    pub min_players: i32,
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+}
+
+fn default_hours() -> i32 {
+    TRENDING_DEFAULT_HOURS
+}
+
+fn default_limit() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
+
+#[derive(Serialize, Deserialize, Debug, FromRow)]
+pub struct TrendingGame {
+    pub appid: String,
+    pub name: String,
+    pub current_avg: f64,
+    pub previous_avg: f64,
+    pub current_peak: i32,
+    pub growth_percent: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetTrendingGamesResponse {
+    pub status: ResponseStatus,
+    pub hours: i32,
+    pub games: Vec<TrendingGame>,
+}
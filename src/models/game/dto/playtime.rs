@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::ResponseStatus;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpsertPlaytimeSnapshotSchema {
+    pub user_id: Uuid,
+    pub game_id: Uuid,
+    pub playtime_forever: i32,
+    pub playtime_2weeks: i32,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PlaytimeQueryParams {
+    pub from: Option<chrono::NaiveDate>,
+    pub to: Option<chrono::NaiveDate>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GamePlaytime {
+    pub appid: String,
+    pub name: String,
+    pub minutes_played: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetPlaytimeResponse {
+    pub status: ResponseStatus,
+    pub from: chrono::NaiveDate,
+    pub to: chrono::NaiveDate,
+    pub total_minutes: i64,
+    pub games: Vec<GamePlaytime>,
+}
@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::models::ResponseStatus;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpsertGamePriceSchema {
+    pub game_id: uuid::Uuid,
+    pub country_code: String,
+    pub currency: String,
+    pub initial_price: i32,
+    pub final_price: i32,
+    pub discount_percent: i32,
+}
+
+// `cc` narrows the response to a single region, e.g. `US`
+#[derive(Deserialize, Debug)]
+pub struct PriceQueryParams {
+    pub cc: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PriceHistoryQueryParams {
+    pub cc: Option<String>,
+    pub from: Option<chrono::NaiveDate>,
+    pub to: Option<chrono::NaiveDate>,
+}
+
+#[derive(Serialize, Deserialize, Debug, FromRow)]
+pub struct CurrentPrice {
+    pub country_code: String,
+    pub currency: String,
+    pub initial_price: i32,
+    pub final_price: i32,
+    pub discount_percent: i32,
+    pub on_sale: bool,
+    // First day of the ongoing discount, `None` when the game is not on sale
+    pub discount_started_on: Option<chrono::NaiveDate>,
+    pub lowest_price: i32,
+    pub is_lowest_ever: bool,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, FromRow)]
+pub struct LowestPrice {
+    pub country_code: String,
+    pub currency: String,
+    pub final_price: i32,
+    pub discount_percent: i32,
+    // First day the lowest price was seen
+    pub snapshot_date: chrono::NaiveDate,
+}
+
+#[derive(Serialize, Deserialize, Debug, FromRow)]
+pub struct PriceSnapshot {
+    pub country_code: String,
+    pub currency: String,
+    pub initial_price: i32,
+    pub final_price: i32,
+    pub discount_percent: i32,
+    pub snapshot_date: chrono::NaiveDate,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetCurrentPricesResponse {
+    pub status: ResponseStatus,
+    pub appid: String,
+    pub prices: Vec<CurrentPrice>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetLowestPricesResponse {
+    pub status: ResponseStatus,
+    pub appid: String,
+    pub prices: Vec<LowestPrice>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetPriceHistoryResponse {
+    pub status: ResponseStatus,
+    pub appid: String,
+    pub history: Vec<PriceSnapshot>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ImportGamePricesResponse {
+    pub status: ResponseStatus,
+    pub appid: String,
+    pub regions_imported: usize,
+    // Regions whose appdetails call failed, the import only fails when all of them do
+    pub regions_failed: usize,
+    // Watchlist alerts fired by the new prices
+    pub alerts_triggered: usize,
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    ResponseStatus, game::game_change::GameChange, pagination::constants::DEFAULT_PAGE_SIZE,
+};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ResyncGameResponse {
+    pub status: ResponseStatus,
+    pub appid: String,
+    pub sync_status: String,
+    pub last_synced_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub changes: Vec<GameChange>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GameChangesQueryParams {
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+}
+
+fn default_limit() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetGameChangesResponse {
+    pub status: ResponseStatus,
+    pub appid: String,
+    pub changes: Vec<GameChange>,
+}
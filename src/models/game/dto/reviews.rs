@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::models::ResponseStatus;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpsertGameReviewSchema {
+    pub game_id: uuid::Uuid,
+    pub review_score: i32,
+    pub review_score_desc: String,
+    pub total_positive: i32,
+    pub total_negative: i32,
+    pub total_reviews: i32,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ReviewHistoryQueryParams {
+    pub from: Option<chrono::NaiveDate>,
+    pub to: Option<chrono::NaiveDate>,
+}
+
+#[derive(Serialize, Deserialize, Debug, FromRow)]
+pub struct ReviewSnapshot {
+    pub review_score: i32,
+    pub review_score_desc: String,
+    pub total_positive: i32,
+    pub total_negative: i32,
+    pub total_reviews: i32,
+    pub snapshot_date: chrono::NaiveDate,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetReviewHistoryResponse {
+    pub status: ResponseStatus,
+    pub appid: String,
+    pub history: Vec<ReviewSnapshot>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ImportGameReviewsResponse {
+    pub status: ResponseStatus,
+    pub appid: String,
+    pub review_score: i32,
+    pub review_score_desc: String,
+    pub total_positive: i32,
+    pub total_negative: i32,
+}
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use crate::models::game::game_movie::GameMovie;
+
 #[derive(Debug, FromRow, Deserialize, Serialize)]
 #[allow(non_snake_case)]
 pub struct GameModel {
@@ -11,6 +13,44 @@ pub struct GameModel {
     pub short_description: Option<String>,
     pub header_image: Option<String>,
     pub screenshots: Option<Vec<String>>,
+    pub is_free: bool,
+    pub price_final: Option<i32>,
+    pub price_currency: Option<String>,
+    pub release_date: Option<chrono::NaiveDate>,
+    pub platform_windows: bool,
+    pub platform_mac: bool,
+    pub platform_linux: bool,
+    pub coming_soon: bool,
+    pub required_age: i32,
+    pub metacritic_score: Option<i32>,
+    pub metacritic_url: Option<String>,
+    pub website: Option<String>,
+    pub supported_languages: Vec<String>,
+    pub background: Option<String>,
+    pub last_synced_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub sync_attempted_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub sync_status: String,
+    pub sync_error: Option<String>,
+    pub app_type: String,
+    pub review_score: Option<i32>,
+    pub review_score_desc: Option<String>,
+    pub review_total_positive: Option<i32>,
+    pub review_total_negative: Option<i32>,
+    pub prices_imported_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl GameModel {
+    pub fn platforms(&self) -> Vec<String> {
+        [
+            (self.platform_windows, "windows"),
+            (self.platform_mac, "mac"),
+            (self.platform_linux, "linux"),
+        ]
+        .into_iter()
+        .filter(|(supported, _)| *supported)
+        .map(|(_, platform)| platform.to_string())
+        .collect()
+    }
 }
 
 #[derive(Debug, FromRow, Deserialize, Serialize)]
@@ -23,11 +63,46 @@ pub struct GameCreationResponse {
     pub screenshots: Option<Vec<String>>,
 }
 
+// Names from the genre, category, developer and publisher lookup tables
 #[derive(Debug, FromRow, Deserialize, Serialize)]
+pub struct GameTaxonomy {
+    pub genres: Vec<String>,
+    pub categories: Vec<String>,
+    pub developers: Vec<String>,
+    pub publishers: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct GetGameResponse {
     pub appid: String,
+    #[serde(rename = "type")]
+    pub app_type: String,
+    // The game a DLC, demo or soundtrack belongs to
+    pub parent_appid: Option<String>,
     pub name: String,
     pub short_description: Option<String>,
     pub header_image: Option<String>,
     pub screenshots: Option<Vec<String>>,
+    pub is_free: bool,
+    pub price_final: Option<i32>,
+    pub price_currency: Option<String>,
+    pub release_date: Option<chrono::NaiveDate>,
+    pub coming_soon: bool,
+    pub required_age: i32,
+    pub metacritic_score: Option<i32>,
+    pub metacritic_url: Option<String>,
+    // Latest appreviews summary, `None` until the reviews were first imported
+    pub review_score: Option<i32>,
+    pub review_score_desc: Option<String>,
+    pub review_total_positive: Option<i32>,
+    pub review_total_negative: Option<i32>,
+    pub website: Option<String>,
+    pub supported_languages: Vec<String>,
+    pub background: Option<String>,
+    pub platforms: Vec<String>,
+    #[serde(flatten)]
+    pub taxonomy: GameTaxonomy,
+    pub movies: Vec<GameMovie>,
+    pub sync_status: String,
+    pub last_synced_at: Option<chrono::DateTime<chrono::Utc>>,
 }
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+// A store field that changed between two syncs, values are stored as JSON
+#[derive(Debug, FromRow, Deserialize, Serialize)]
+pub struct GameChange {
+    pub field: String,
+    pub old_value: Option<serde_json::Value>,
+    pub new_value: Option<serde_json::Value>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
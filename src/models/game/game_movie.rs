@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+// Trailers from appdetails, only the 480p and max resolution streams are kept
+#[derive(Debug, FromRow, Deserialize, Serialize)]
+pub struct GameMovie {
+    pub steam_movie_id: i32,
+    pub name: String,
+    pub thumbnail: String,
+    pub webm_480: Option<String>,
+    pub webm_max: Option<String>,
+    pub mp4_480: Option<String>,
+    pub mp4_max: Option<String>,
+    pub highlight: bool,
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, FromRow, Deserialize, Serialize)]
+pub struct GamePriceModel {
+    pub id: Uuid,
+    pub game_id: Uuid,
+    pub country_code: String,
+    pub currency: String,
+    pub initial_price: i32,
+    pub final_price: i32,
+    pub discount_percent: i32,
+    pub snapshot_date: chrono::NaiveDate,
+    #[serde(rename = "createdAt")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
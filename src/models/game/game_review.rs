@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, FromRow, Deserialize, Serialize)]
+pub struct GameReviewModel {
+    pub id: Uuid,
+    pub game_id: Uuid,
+    pub review_score: i32,
+    pub review_score_desc: String,
+    pub total_positive: i32,
+    pub total_negative: i32,
+    pub total_reviews: i32,
+    pub snapshot_date: chrono::NaiveDate,
+    #[serde(rename = "createdAt")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
@@ -1,3 +1,11 @@
+pub mod constants;
 pub mod dto;
 pub mod game;
+pub mod game_change;
+pub mod game_movie;
+pub mod game_price;
+pub mod game_review;
+pub mod pagination;
+pub mod playtime_snapshot;
+pub mod sync_status;
 pub mod user_games;
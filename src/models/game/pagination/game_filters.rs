@@ -0,0 +1,146 @@
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone)]
+pub struct GameFilters {
+    pub name: Option<String>,
+    pub genre: Option<String>,
+    pub category: Option<String>,
+    pub platform: Option<GamePlatform>,
+    // appdetails type, e.g. `game`, `dlc`, `demo`, `music`
+    pub app_type: Option<String>,
+    // Latest recorded US price, in cents
+    pub min_price: Option<i32>,
+    pub max_price: Option<i32>,
+    pub free: Option<bool>,
+    pub release_year: Option<i32>,
+    // Tracked (not deleted) users owning the game, and those with any playtime on it
+    pub min_owners: Option<i64>,
+    pub min_players: Option<i64>,
+    // Steam review rating from 0 (no user reviews) to 9 (overwhelmingly positive)
+    pub min_review_score: Option<i32>,
+}
+
+impl GameFilters {
+    // Whether a catalog price satisfies the price filters, games without a US price only
+    // match when no price filter is set
+    pub fn matches_price(&self, price: Option<i32>) -> bool {
+        if self.min_price.is_none() && self.max_price.is_none() {
+            return true;
+        }
+        price.is_some_and(|price| {
+            self.min_price.is_none_or(|min| price >= min)
+                && self.max_price.is_none_or(|max| price <= max)
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum GamePlatform {
+    Windows,
+    Mac,
+    Linux,
+}
+
+impl GamePlatform {
+    pub fn as_column(&self) -> &'static str {
+        match self {
+            GamePlatform::Windows => "platform_windows",
+            GamePlatform::Mac => "platform_mac",
+            GamePlatform::Linux => "platform_linux",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum GameSortField {
+    #[default]
+    Name,
+    ReleaseDate,
+    // Number of tracked users owning the game
+    Popularity,
+    // Steam review rating, games without a review summary sort first
+    ReviewScore,
+}
+
+impl GameSortField {
+    // Only these expressions ever reach the ORDER BY clause, they refer to the
+    // catalog subquery columns. Games without a known release date sort first.
+    pub fn as_sort_expression(&self) -> &'static str {
+        match self {
+            GameSortField::Name => "name",
+            GameSortField::ReleaseDate => "COALESCE(release_date, '0001-01-01'::DATE)",
+            GameSortField::Popularity => "owners",
+            GameSortField::ReviewScore => "COALESCE(review_score, -1)",
+        }
+    }
+
+    pub fn as_sql_type(&self) -> &'static str {
+        match self {
+            GameSortField::Name => "TEXT",
+            GameSortField::ReleaseDate => "DATE",
+            GameSortField::Popularity => "BIGINT",
+            GameSortField::ReviewScore => "INTEGER",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_price_without_price_filters() {
+        let filters = GameFilters::default();
+
+        assert!(filters.matches_price(None));
+        assert!(filters.matches_price(Some(999)));
+    }
+
+    #[test]
+    fn matches_price_within_bounds() {
+        let filters = GameFilters {
+            min_price: Some(500),
+            max_price: Some(1000),
+            ..Default::default()
+        };
+
+        assert!(filters.matches_price(Some(500)));
+        assert!(filters.matches_price(Some(1000)));
+        assert!(!filters.matches_price(Some(499)));
+        assert!(!filters.matches_price(Some(1001)));
+        assert!(!filters.matches_price(None));
+    }
+
+    fn sort(value: &str) -> Result<GameSortField, serde_json::Error> {
+        serde_json::from_value(serde_json::Value::String(value.to_string()))
+    }
+
+    #[test]
+    fn sort_fields_map_to_fixed_expressions() {
+        let expected = [
+            ("name", "name", "TEXT"),
+            (
+                "release_date",
+                "COALESCE(release_date, '0001-01-01'::DATE)",
+                "DATE",
+            ),
+            ("popularity", "owners", "BIGINT"),
+            ("review_score", "COALESCE(review_score, -1)", "INTEGER"),
+        ];
+
+        for (value, expression, sql_type) in expected {
+            let field = sort(value).unwrap();
+            assert_eq!(field.as_sort_expression(), expression);
+            assert_eq!(field.as_sql_type(), sql_type);
+        }
+    }
+
+    #[test]
+    fn sort_rejects_values_outside_the_whitelist() {
+        assert!(sort("price_final").is_err());
+        assert!(sort("name; DROP TABLE games").is_err());
+        assert!(sort("Name").is_err());
+    }
+}
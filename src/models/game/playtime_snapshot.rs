@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, FromRow, Deserialize, Serialize)]
+#[allow(non_snake_case)]
+pub struct PlaytimeSnapshotModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub game_id: Uuid,
+    pub snapshot_date: chrono::NaiveDate,
+    pub playtime_forever: i32,
+    pub playtime_2weeks: i32,
+    #[serde(rename = "createdAt")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
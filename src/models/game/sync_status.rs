@@ -0,0 +1,17 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameSyncStatus {
+    Synced,
+    Failed,
+    // appdetails no longer knows the appid, e.g. delisted or region locked games
+    NotFound,
+}
+
+impl GameSyncStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GameSyncStatus::Synced => "synced",
+            GameSyncStatus::Failed => "failed",
+            GameSyncStatus::NotFound => "not_found",
+        }
+    }
+}
@@ -8,4 +8,9 @@ pub struct UserGamesModel {
     pub id: Uuid,
     pub user_id: Uuid,
     pub game_id: Uuid,
+    pub playtime_forever: i32,
+    pub playtime_2weeks: i32,
+    pub last_played_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::ResponseStatus;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserGroup {
+    pub gid: String,
+    pub tracked_members: i64,
+    pub tracked_since: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetUserGroupsResponse {
+    pub status: ResponseStatus,
+    pub total: usize,
+    pub groups: Vec<UserGroup>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GroupMember {
+    pub steam_id: String,
+    pub username: String,
+    pub avatar: String,
+    pub pf_url: String,
+    pub current_game: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetGroupMembersResponse {
+    pub status: ResponseStatus,
+    pub gid: String,
+    pub total: usize,
+    pub members: Vec<GroupMember>,
+}
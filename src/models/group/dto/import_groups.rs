@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::ResponseStatus;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ImportGroupsResponse {
+    pub status: ResponseStatus,
+    pub imported: usize,
+}
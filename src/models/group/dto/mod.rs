@@ -0,0 +1,5 @@
+pub mod get_groups;
+pub mod import_groups;
+
+pub use get_groups::{GetGroupMembersResponse, GetUserGroupsResponse, GroupMember, UserGroup};
+pub use import_groups::ImportGroupsResponse;
@@ -0,0 +1,3 @@
+pub mod dto;
+#[allow(clippy::module_inception)]
+pub mod group;
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::models::{
+    ResponseStatus, pagination::constants::DEFAULT_PAGE_SIZE, user::dto::get_users::PaginationMeta,
+};
+
+#[derive(Deserialize, Debug)]
+pub struct InventoryItemsQueryParams {
+    pub app_id: Option<String>,
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    pub cursor: Option<String>,
+    #[serde(default)]
+    pub include_total: bool,
+}
+
+fn default_limit() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
+
+#[derive(Serialize, Deserialize, Debug, FromRow)]
+pub struct InventoryItem {
+    pub app_id: String,
+    pub classid: String,
+    pub icon: String,
+    pub name: String,
+    pub color: String,
+    pub item_type: String,
+    pub description: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct GetInventoryItemsResponse {
+    pub status: ResponseStatus,
+    pub items: Vec<InventoryItem>,
+    pub pagination: PaginationMeta,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+}
@@ -1,3 +1,6 @@
 pub mod create_items;
 
-pub use create_items::{CreateItemSchema,ItemCreationResponse};
\ No newline at end of file
+pub use create_items::{CreateItemSchema,ItemCreationResponse};
+
+pub mod get_items;
+pub use get_items::{GetInventoryItemsResponse, InventoryItem, InventoryItemsQueryParams};
@@ -12,4 +12,28 @@ pub mod game;
 
 pub mod inventory;
 
-pub mod inventory_items;
\ No newline at end of file
+pub mod inventory_items;
+
+pub mod friend;
+
+pub mod achievement;
+
+pub mod ban;
+
+pub mod progression;
+
+pub mod group;
+
+pub mod pagination;
+
+pub mod search;
+
+pub mod profile;
+
+pub mod privacy;
+
+pub mod watchlist;
+
+pub mod steam_app;
+
+pub mod news;
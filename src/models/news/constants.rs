@@ -0,0 +1,2 @@
+// Latest articles requested from GetNewsForApp on every import
+pub const NEWS_ITEMS_PER_IMPORT: i32 = 20;
@@ -0,0 +1,4 @@
+pub mod news;
+pub use news::{
+    GetNewsResponse, ImportGameNewsResponse, NewsItem, NewsQueryParams, UpsertNewsItemSchema,
+};
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::models::{
+    ResponseStatus, pagination::constants::DEFAULT_PAGE_SIZE, user::dto::get_users::PaginationMeta,
+};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpsertNewsItemSchema {
+    pub game_id: Uuid,
+    pub gid: String,
+    pub title: String,
+    pub url: String,
+    pub author: Option<String>,
+    pub contents: String,
+    pub feed_label: String,
+    pub feed_name: String,
+    pub is_external_url: bool,
+    pub published_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct NewsQueryParams {
+    // Steam feed name, e.g. `steam_community_announcements`
+    pub feed: Option<String>,
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    pub cursor: Option<String>,
+    #[serde(default)]
+    pub include_total: bool,
+}
+
+fn default_limit() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
+
+#[derive(Serialize, Deserialize, Debug, FromRow)]
+pub struct NewsItem {
+    pub gid: String,
+    pub appid: String,
+    pub game_name: String,
+    pub title: String,
+    pub url: String,
+    pub author: Option<String>,
+    pub contents: String,
+    pub feed_label: String,
+    pub feed_name: String,
+    pub is_external_url: bool,
+    pub published_at: chrono::DateTime<chrono::Utc>,
+}
+
+// Newest articles first
+#[derive(Serialize, Debug)]
+pub struct GetNewsResponse {
+    pub status: ResponseStatus,
+    pub news: Vec<NewsItem>,
+    pub pagination: PaginationMeta,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ImportGameNewsResponse {
+    pub status: ResponseStatus,
+    pub appid: String,
+    pub fetched: usize,
+    // Articles that were not stored before, by gid
+    pub new_items: usize,
+}
@@ -0,0 +1,3 @@
+pub mod constants;
+pub mod dto;
+pub mod news_scope;
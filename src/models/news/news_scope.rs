@@ -0,0 +1,8 @@
+use uuid::Uuid;
+
+// Whose news a listing covers, a single game or every game in a user's library
+#[derive(Debug, Clone, Copy)]
+pub enum NewsScope {
+    Game(Uuid),
+    User(Uuid),
+}
@@ -0,0 +1,5 @@
+pub const MIN_PAGE_SIZE: i64 = 1;
+pub const MAX_PAGE_SIZE: i64 = 100;
+pub const DEFAULT_PAGE_SIZE: i64 = 20;
+// First page of the listings that still accept a page number
+pub const DEFAULT_PAGE: i64 = 1;
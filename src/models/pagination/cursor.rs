@@ -0,0 +1,142 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CursorDirection {
+    Next,
+    Prev,
+}
+
+// Opaque to clients, `key` ties the cursor to the sort it was issued for
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Cursor {
+    pub key: String,
+    pub value: String,
+    pub id: Uuid,
+    pub direction: CursorDirection,
+}
+
+impl Cursor {
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    pub fn decode(encoded: &str, key: &str) -> Option<Cursor> {
+        let json = URL_SAFE_NO_PAD.decode(encoded).ok()?;
+        let cursor: Cursor = serde_json::from_slice(&json).ok()?;
+
+        (cursor.key == key).then_some(cursor)
+    }
+}
+
+// A listing row plus the sort value and id it is keyed on
+#[derive(Debug, FromRow)]
+pub struct CursorRow<T> {
+    #[sqlx(flatten)]
+    pub item: T,
+    pub cursor_value: String,
+    pub cursor_id: Uuid,
+}
+
+#[derive(Debug)]
+pub struct CursorPage<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+}
+
+impl<T> CursorPage<T> {
+    // Rows are expected to hold up to `limit + 1` entries, the extra one only
+    // tells whether another page exists in the requested direction
+    pub fn from_rows(
+        mut rows: Vec<CursorRow<T>>,
+        limit: i64,
+        key: &str,
+        cursor: Option<&Cursor>,
+        has_previous: bool,
+    ) -> Self {
+        let has_more = rows.len() as i64 > limit;
+        rows.truncate(limit.max(0) as usize);
+
+        let backwards = cursor.is_some_and(|c| c.direction == CursorDirection::Prev);
+        if backwards {
+            rows.reverse();
+        }
+
+        let (has_next, has_prev) = if backwards {
+            (true, has_more)
+        } else {
+            (has_more, has_previous || cursor.is_some())
+        };
+
+        let make_cursor = |row: &CursorRow<T>, direction| {
+            Cursor {
+                key: key.to_string(),
+                value: row.cursor_value.clone(),
+                id: row.cursor_id,
+                direction,
+            }
+            .encode()
+        };
+
+        let next_cursor = rows
+            .last()
+            .filter(|_| has_next)
+            .map(|row| make_cursor(row, CursorDirection::Next));
+        let prev_cursor = rows
+            .first()
+            .filter(|_| has_prev)
+            .map(|row| make_cursor(row, CursorDirection::Prev));
+
+        CursorPage {
+            items: rows.into_iter().map(|row| row.item).collect(),
+            next_cursor,
+            prev_cursor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursor() -> Cursor {
+        Cursor {
+            key: "users:username:ASC".to_string(),
+            value: "alice".to_string(),
+            id: Uuid::nil(),
+            direction: CursorDirection::Next,
+        }
+    }
+
+    #[test]
+    fn decode_round_trips_an_encoded_cursor() {
+        let decoded = Cursor::decode(&cursor().encode(), "users:username:ASC").unwrap();
+
+        assert_eq!(decoded.key, "users:username:ASC");
+        assert_eq!(decoded.value, "alice");
+        assert_eq!(decoded.id, Uuid::nil());
+        assert_eq!(decoded.direction, CursorDirection::Next);
+    }
+
+    #[test]
+    fn decode_rejects_a_cursor_issued_for_another_sort() {
+        assert!(Cursor::decode(&cursor().encode(), "users:created_at:ASC").is_none());
+    }
+
+    #[test]
+    fn decode_rejects_tampered_input() {
+        let encoded = cursor().encode();
+
+        assert!(Cursor::decode("not a cursor", "users:username:ASC").is_none());
+        assert!(Cursor::decode(&encoded[1..], "users:username:ASC").is_none());
+        assert!(Cursor::decode(&format!("{}!", encoded), "users:username:ASC").is_none());
+
+        let not_a_cursor = URL_SAFE_NO_PAD.encode(br#"{"key":"users:username:ASC"}"#);
+        assert!(Cursor::decode(&not_a_cursor, "users:username:ASC").is_none());
+    }
+}
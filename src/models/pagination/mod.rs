@@ -0,0 +1,8 @@
+pub mod constants;
+pub mod cursor;
+pub mod page_request;
+pub mod sort_order;
+
+pub use cursor::{Cursor, CursorDirection, CursorPage, CursorRow};
+pub use page_request::PageRequest;
+pub use sort_order::SortOrder;
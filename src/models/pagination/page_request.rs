@@ -0,0 +1,6 @@
+#[derive(Debug, Clone)]
+pub struct PageRequest {
+    pub limit: i64,
+    pub cursor: Option<String>,
+    pub include_total: bool,
+}
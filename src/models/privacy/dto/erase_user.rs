@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::models::ResponseStatus;
+
+#[derive(Serialize, Deserialize, Debug, FromRow)]
+pub struct ErasedRows {
+    pub games: i64,
+    pub playtime_snapshots: i64,
+    pub inventory_items: i64,
+    pub friends: i64,
+    // Rows in other users' friend lists pointing at the erased account
+    pub friend_references: i64,
+    pub achievements: i64,
+    pub bans: i64,
+    pub ban_history: i64,
+    pub progression: i64,
+    pub badges: i64,
+    pub groups: i64,
+    pub watchlist: i64,
+    pub price_alerts: i64,
+}
+
+impl ErasedRows {
+    pub fn total(&self) -> i64 {
+        // +1 for the user row itself
+        1 + self.games
+            + self.playtime_snapshots
+            + self.inventory_items
+            + self.friends
+            + self.friend_references
+            + self.achievements
+            + self.bans
+            + self.ban_history
+            + self.progression
+            + self.badges
+            + self.groups
+            + self.watchlist
+            + self.price_alerts
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EraseUserResponse {
+    pub status: ResponseStatus,
+    pub steam_id: String,
+    pub rows_erased: i64,
+    pub erased: ErasedRows,
+}
@@ -0,0 +1,14 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Json,
+    Zip,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ExportQueryParams {
+    pub format: Option<ExportFormat>,
+}
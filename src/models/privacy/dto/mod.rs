@@ -0,0 +1,5 @@
+pub mod erase_user;
+pub mod export_user;
+
+pub use erase_user::{EraseUserResponse, ErasedRows};
+pub use export_user::{ExportFormat, ExportQueryParams};
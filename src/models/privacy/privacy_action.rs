@@ -0,0 +1,14 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PrivacyAction {
+    Export,
+    Erase,
+}
+
+impl PrivacyAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PrivacyAction::Export => "export",
+            PrivacyAction::Erase => "erase",
+        }
+    }
+}
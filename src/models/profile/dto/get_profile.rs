@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::models::{ResponseStatus, dto::GetUser, game::dto::UserGame};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProfileSection {
+    User,
+    Games,
+    Inventory,
+    Sync,
+}
+
+impl ProfileSection {
+    pub const ALL: [ProfileSection; 4] = [
+        ProfileSection::User,
+        ProfileSection::Games,
+        ProfileSection::Inventory,
+        ProfileSection::Sync,
+    ];
+
+    pub fn parse(section: &str) -> Option<ProfileSection> {
+        match section {
+            "user" => Some(ProfileSection::User),
+            "games" => Some(ProfileSection::Games),
+            "inventory" => Some(ProfileSection::Inventory),
+            "sync" => Some(ProfileSection::Sync),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ProfileQueryParams {
+    // Comma separated sections, every section is returned when missing
+    pub include: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, FromRow)]
+pub struct InventoryTypeCount {
+    // Already carried by the parent InventoryAppSummary
+    #[serde(skip_serializing)]
+    pub app_id: String,
+    pub item_type: String,
+    pub item_count: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct InventoryAppSummary {
+    pub app_id: String,
+    pub total_items: i64,
+    pub item_types: Vec<InventoryTypeCount>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct InventorySummary {
+    pub total_items: i64,
+    pub apps: Vec<InventoryAppSummary>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SyncTimes {
+    pub profile_synced_at: chrono::DateTime<chrono::Utc>,
+    pub games_synced_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub inventory_synced_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub friends_synced_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub bans_synced_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub progression_synced_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetUserProfileResponse {
+    pub status: ResponseStatus,
+    pub user: Option<GetUser>,
+    pub games: Option<Vec<UserGame>>,
+    pub inventory: Option<InventorySummary>,
+    pub sync: Option<SyncTimes>,
+}
@@ -0,0 +1,6 @@
+pub mod get_profile;
+
+pub use get_profile::{
+    GetUserProfileResponse, InventoryAppSummary, InventorySummary, InventoryTypeCount,
+    ProfileQueryParams, ProfileSection, SyncTimes,
+};
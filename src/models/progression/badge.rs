@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, FromRow, Deserialize, Serialize)]
+#[allow(non_snake_case)]
+pub struct UserBadgeModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub badge_id: i32,
+    pub appid: i32,
+    pub level: i32,
+    pub xp: i32,
+    pub scarcity: i32,
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(rename = "createdAt")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
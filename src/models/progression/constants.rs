@@ -0,0 +1,3 @@
+pub const MIN_LEADERBOARD_LIMIT: i64 = 1;
+pub const MAX_LEADERBOARD_LIMIT: i64 = 100;
+pub const DEFAULT_LEADERBOARD_LIMIT: i64 = 25;
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::ResponseStatus;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ProgressionSummary {
+    pub steam_level: i32,
+    pub player_xp: i32,
+    pub xp_needed_to_level_up: i32,
+    pub xp_needed_current_level: i32,
+    pub badge_count: i64,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Badge {
+    pub badge_id: i32,
+    pub appid: Option<i32>,
+    pub level: i32,
+    pub xp: i32,
+    pub scarcity: i32,
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetUserBadgesResponse {
+    pub status: ResponseStatus,
+    pub total: usize,
+    pub badges: Vec<Badge>,
+}
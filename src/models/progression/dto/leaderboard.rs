@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{ResponseStatus, progression::constants::DEFAULT_LEADERBOARD_LIMIT};
+
+#[derive(Deserialize, Debug, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardSort {
+    #[default]
+    Level,
+    Xp,
+}
+
+impl LeaderboardSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LeaderboardSort::Level => "level",
+            LeaderboardSort::Xp => "xp",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LeaderboardQueryParams {
+    #[serde(default)]
+    pub sort: LeaderboardSort,
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+}
+
+fn default_limit() -> i64 {
+    DEFAULT_LEADERBOARD_LIMIT
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LeaderboardEntry {
+    pub rank: i64,
+    pub steam_id: String,
+    pub username: String,
+    pub avatar: String,
+    pub steam_level: i32,
+    pub player_xp: i32,
+    pub badge_count: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetLeaderboardResponse {
+    pub status: ResponseStatus,
+    pub leaderboard: Vec<LeaderboardEntry>,
+}
@@ -0,0 +1,9 @@
+pub mod get_progression;
+pub mod leaderboard;
+pub mod upsert_progression;
+
+pub use get_progression::{Badge, GetUserBadgesResponse, ProgressionSummary};
+pub use leaderboard::{
+    GetLeaderboardResponse, LeaderboardEntry, LeaderboardQueryParams, LeaderboardSort,
+};
+pub use upsert_progression::{UpsertUserBadgeSchema, UpsertUserProgressionSchema};
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpsertUserProgressionSchema {
+    pub user_id: Uuid,
+    pub steam_level: i32,
+    pub player_xp: i32,
+    pub xp_needed_to_level_up: i32,
+    pub xp_needed_current_level: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpsertUserBadgeSchema {
+    pub user_id: Uuid,
+    pub badge_id: i32,
+    pub appid: i32,
+    pub level: i32,
+    pub xp: i32,
+    pub scarcity: i32,
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
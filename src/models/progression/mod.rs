@@ -0,0 +1,5 @@
+pub mod badge;
+pub mod constants;
+pub mod dto;
+#[allow(clippy::module_inception)]
+pub mod progression;
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, FromRow, Deserialize, Serialize)]
+#[allow(non_snake_case)]
+pub struct UserProgressionModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub steam_level: i32,
+    pub player_xp: i32,
+    pub xp_needed_to_level_up: i32,
+    pub xp_needed_current_level: i32,
+    #[serde(rename = "createdAt")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
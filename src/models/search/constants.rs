@@ -0,0 +1,4 @@
+pub const MIN_SEARCH_QUERY_LENGTH: usize = 2;
+pub const MIN_SEARCH_LIMIT: i64 = 1;
+pub const MAX_SEARCH_LIMIT: i64 = 50;
+pub const DEFAULT_SEARCH_LIMIT: i64 = 20;
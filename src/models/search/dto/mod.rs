@@ -0,0 +1,3 @@
+pub mod search;
+
+pub use search::{SearchQueryParams, SearchResponse, SearchResult, SearchResultType};
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{ResponseStatus, search::constants::DEFAULT_SEARCH_LIMIT};
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchResultType {
+    User,
+    Game,
+    Item,
+}
+
+impl SearchResultType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchResultType::User => "user",
+            SearchResultType::Game => "game",
+            SearchResultType::Item => "item",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SearchQueryParams {
+    #[serde(default)]
+    pub q: String,
+    // Restricts results to a single type
+    #[serde(rename = "type")]
+    pub result_type: Option<SearchResultType>,
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+}
+
+fn default_limit() -> i64 {
+    DEFAULT_SEARCH_LIMIT
+}
+
+// `id` is the steam id for users, the appid for games and the item uuid for items
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SearchResult {
+    #[serde(rename = "type")]
+    pub result_type: String,
+    pub id: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub rank: f32,
+    pub highlight: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SearchResponse {
+    pub status: ResponseStatus,
+    pub query: String,
+    pub total: usize,
+    pub results: Vec<SearchResult>,
+}
@@ -0,0 +1,4 @@
+// Rows upserted per statement when importing GetAppList
+pub const STEAM_APPS_IMPORT_CHUNK_SIZE: usize = 5000;
+// Candidates reported back when a game name does not resolve to a single app
+pub const MAX_NAME_CANDIDATES: i64 = 10;
@@ -0,0 +1,2 @@
+pub mod steam_apps;
+pub use steam_apps::{GetSteamAppsResponse, ImportSteamAppsResponse, SteamAppsQueryParams};
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    ResponseStatus, search::constants::DEFAULT_SEARCH_LIMIT, steam_app::steam_app::SteamApp,
+};
+
+#[derive(Deserialize, Debug)]
+pub struct SteamAppsQueryParams {
+    #[serde(default)]
+    pub q: String,
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+}
+
+fn default_limit() -> i64 {
+    DEFAULT_SEARCH_LIMIT
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetSteamAppsResponse {
+    pub status: ResponseStatus,
+    pub query: String,
+    pub total: usize,
+    pub apps: Vec<SteamApp>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ImportSteamAppsResponse {
+    pub status: ResponseStatus,
+    // Apps listed by GetAppList, and those that were new or renamed
+    pub total_apps: usize,
+    pub updated: u64,
+}
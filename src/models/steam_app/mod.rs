@@ -0,0 +1,4 @@
+pub mod constants;
+pub mod dto;
+#[allow(clippy::module_inception)]
+pub mod steam_app;
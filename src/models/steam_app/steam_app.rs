@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+// `stored` tells whether the app is already in the games table
+#[derive(Debug, FromRow, Deserialize, Serialize)]
+pub struct SteamApp {
+    pub appid: String,
+    pub name: String,
+    pub stored: bool,
+}
@@ -1,4 +1,6 @@
-pub const MIN_PAGE_SIZE: i64 = 1;
-pub const MAX_PAGE_SIZE: i64 = 10;
-pub const DEFAULT_PAGE: i64 = 1;
-pub const DEFAULT_PAGE_SIZE: i64 = 10;
+// Soft deleted users are purged for good after this many days
+pub const DELETED_USER_RETENTION_DAYS: i32 = 30;
+
+// Steam's communityvisibilitystate for public profiles, private and friends only
+// profiles hide everything past the basic summary
+pub const PUBLIC_VISIBILITY: i32 = 3;
@@ -1,4 +1,6 @@
-use crate::models::response_status::ResponseStatus;
+use crate::models::{
+    ban::dto::BanStatus, progression::dto::ProgressionSummary, response_status::ResponseStatus,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -11,7 +13,7 @@ pub struct GetUser {
     pub current_game: Option<String>,
     pub persona_state: i32,
     pub visibility: i32,
-    pub steam_created_at: chrono::DateTime<chrono::Utc>,
+    pub steam_created_at: Option<chrono::DateTime<chrono::Utc>>,
     pub gameid: Option<String>,
 }
 
@@ -19,4 +21,6 @@ pub struct GetUser {
 pub struct GetUserResponse {
     pub status: ResponseStatus,
     pub user: GetUser,
+    pub bans: Option<BanStatus>,
+    pub progression: Option<ProgressionSummary>,
 }
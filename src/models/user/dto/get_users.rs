@@ -2,27 +2,57 @@ use serde::{Deserialize, Serialize};
 
 use crate::models::ResponseStatus;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, sqlx::FromRow)]
 pub struct GetUsers {
     pub steam_id: String,
     pub username: String,
     pub avatar: String,
     pub pf_url: String,
     pub current_game: Option<String>,
+    pub visibility: i32,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct PaginationMeta {
     pub total_in_page: usize,
-    pub total: i64,
-    pub total_pages: i64,
-    pub current_page: i64,
+    // Only counted when `include_total=true` is requested
+    pub total: Option<i64>,
+    pub total_pages: Option<i64>,
+    // Not set when paging with a cursor
+    pub current_page: Option<i64>,
     pub page_size: i64,
 }
 
+impl PaginationMeta {
+    pub fn new(
+        total_in_page: usize,
+        total: Option<i64>,
+        current_page: Option<i64>,
+        page_size: i64,
+    ) -> Self {
+        let total_pages = total.map(|total| {
+            if total == 0 {
+                1
+            } else {
+                (total + page_size - 1) / page_size
+            }
+        });
+
+        PaginationMeta {
+            total_in_page,
+            total,
+            total_pages,
+            current_page,
+            page_size,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct GetUsersResponse {
     pub status: ResponseStatus,
     pub users: Vec<GetUsers>,
     pub pagination: PaginationMeta,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
 }
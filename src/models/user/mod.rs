@@ -1,4 +1,5 @@
 pub mod constants;
 pub mod dto;
 pub mod pagination;
-pub mod user;
\ No newline at end of file
+pub mod user;
+pub mod viewer;
@@ -1 +1,2 @@
+pub mod user_filters;
 pub mod user_pagination;
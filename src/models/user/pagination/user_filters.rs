@@ -0,0 +1,110 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone)]
+pub struct UserFilters {
+    pub username: Option<String>,
+    pub banned: Option<bool>,
+    pub group: Option<String>,
+    pub country: Option<String>,
+    pub persona_state: Option<i32>,
+    pub visibility: Option<i32>,
+    pub current_game: Option<String>,
+    pub gameid: Option<String>,
+    pub steam_created_from: Option<NaiveDate>,
+    pub steam_created_to: Option<NaiveDate>,
+    pub created_from: Option<NaiveDate>,
+    pub created_to: Option<NaiveDate>,
+    // Set for non-admin callers whose filters or sort touch private profile details
+    pub public_profiles_only: bool,
+}
+
+impl UserFilters {
+    // Fields Steam hides on private profiles
+    pub fn has_profile_detail_filters(&self) -> bool {
+        self.group.is_some()
+            || self.country.is_some()
+            || self.current_game.is_some()
+            || self.gameid.is_some()
+            || self.steam_created_from.is_some()
+            || self.steam_created_to.is_some()
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UserSortField {
+    #[default]
+    Username,
+    Country,
+    PersonaState,
+    Visibility,
+    SteamCreatedAt,
+    CreatedAt,
+}
+
+impl UserSortField {
+    // Only these expressions ever reach the ORDER BY clause. Nullable columns are
+    // coalesced so the keyset comparison stays total.
+    pub fn as_sort_expression(&self) -> &'static str {
+        match self {
+            UserSortField::Username => "username",
+            UserSortField::Country => "COALESCE(country, '')",
+            UserSortField::PersonaState => "persona_state",
+            UserSortField::Visibility => "visibility",
+            UserSortField::SteamCreatedAt => "COALESCE(steam_created_at, 'epoch'::timestamptz)",
+            UserSortField::CreatedAt => "created_at",
+        }
+    }
+
+    // Cursors carry the sort value, so these would leak details of private profiles
+    pub fn is_profile_detail(&self) -> bool {
+        matches!(self, UserSortField::Country | UserSortField::SteamCreatedAt)
+    }
+
+    pub fn as_sql_type(&self) -> &'static str {
+        match self {
+            UserSortField::Username | UserSortField::Country => "TEXT",
+            UserSortField::PersonaState | UserSortField::Visibility => "INTEGER",
+            UserSortField::SteamCreatedAt | UserSortField::CreatedAt => "TIMESTAMPTZ",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sort(value: &str) -> Result<UserSortField, serde_json::Error> {
+        serde_json::from_value(serde_json::Value::String(value.to_string()))
+    }
+
+    #[test]
+    fn sort_fields_map_to_fixed_expressions() {
+        let expected = [
+            ("username", "username", "TEXT"),
+            ("country", "COALESCE(country, '')", "TEXT"),
+            ("persona_state", "persona_state", "INTEGER"),
+            ("visibility", "visibility", "INTEGER"),
+            (
+                "steam_created_at",
+                "COALESCE(steam_created_at, 'epoch'::timestamptz)",
+                "TIMESTAMPTZ",
+            ),
+            ("created_at", "created_at", "TIMESTAMPTZ"),
+        ];
+
+        for (value, expression, sql_type) in expected {
+            let field = sort(value).unwrap();
+            assert_eq!(field.as_sort_expression(), expression);
+            assert_eq!(field.as_sql_type(), sql_type);
+        }
+    }
+
+    #[test]
+    fn sort_rejects_values_outside_the_whitelist() {
+        assert!(sort("password").is_err());
+        assert!(sort("username; DROP TABLE users").is_err());
+        assert!(sort("Username").is_err());
+    }
+}
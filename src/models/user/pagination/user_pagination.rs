@@ -1,4 +1,8 @@
-use crate::models::user::constants::{DEFAULT_PAGE, DEFAULT_PAGE_SIZE};
+use crate::models::pagination::SortOrder;
+use crate::models::pagination::constants::{DEFAULT_PAGE, DEFAULT_PAGE_SIZE};
+use crate::models::user::pagination::user_filters::{UserFilters, UserSortField};
+use crate::models::user::viewer::Viewer;
+use chrono::NaiveDate;
 use serde::Deserialize;
 #[derive(Debug, Deserialize)]
 pub struct QueryParams {
@@ -7,6 +11,52 @@ pub struct QueryParams {
     #[serde(default = "default_limit")]
     pub limit: i64,
     pub username: Option<String>,
+    // `true` keeps accounts with any ban, `false` keeps clean accounts
+    pub banned: Option<bool>,
+    // Steam group id (gid) the user belongs to
+    pub group: Option<String>,
+    pub country: Option<String>,
+    pub persona_state: Option<i32>,
+    pub visibility: Option<i32>,
+    pub current_game: Option<String>,
+    pub gameid: Option<String>,
+    pub steam_created_from: Option<NaiveDate>,
+    pub steam_created_to: Option<NaiveDate>,
+    pub created_from: Option<NaiveDate>,
+    pub created_to: Option<NaiveDate>,
+    #[serde(default)]
+    pub sort: UserSortField,
+    #[serde(default)]
+    pub order: SortOrder,
+    // Opaque `next_cursor`/`prev_cursor` from a previous page, takes precedence over `page`
+    pub cursor: Option<String>,
+    #[serde(default)]
+    pub include_total: bool,
+}
+
+impl QueryParams {
+    pub fn filters(&self, viewer: Viewer) -> UserFilters {
+        let mut filters = UserFilters {
+            username: self.username.clone(),
+            banned: self.banned,
+            group: self.group.clone(),
+            country: self.country.clone(),
+            persona_state: self.persona_state,
+            visibility: self.visibility,
+            current_game: self.current_game.clone(),
+            gameid: self.gameid.clone(),
+            steam_created_from: self.steam_created_from,
+            steam_created_to: self.steam_created_to,
+            created_from: self.created_from,
+            created_to: self.created_to,
+            public_profiles_only: false,
+        };
+
+        filters.public_profiles_only = !viewer.is_admin()
+            && (filters.has_profile_detail_filters() || self.sort.is_profile_detail());
+
+        filters
+    }
 }
 
 fn default_page() -> i64 {
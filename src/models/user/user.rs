@@ -14,7 +14,7 @@ pub struct UserModel {
     pub visibility: i32,
 
     #[serde(rename = "steamCreatedAt")]
-    pub steam_created_at: chrono::DateTime<chrono::Utc>,
+    pub steam_created_at: Option<chrono::DateTime<chrono::Utc>>,
     pub gameid: Option<String>,
     pub current_game: Option<String>,
     pub country: Option<String>,
@@ -23,4 +23,6 @@ pub struct UserModel {
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(rename = "updatedAt")]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(rename = "deletedAt")]
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
 }
@@ -0,0 +1,19 @@
+use super::constants::PUBLIC_VISIBILITY;
+
+// Who is reading stored user data, resolved from an optional admin bearer token
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Viewer {
+    Admin,
+    Public,
+}
+
+impl Viewer {
+    pub fn is_admin(&self) -> bool {
+        *self == Viewer::Admin
+    }
+
+    // Admins keep seeing stored details after a profile goes private
+    pub fn can_view(&self, visibility: i32) -> bool {
+        self.is_admin() || visibility == PUBLIC_VISIBILITY
+    }
+}
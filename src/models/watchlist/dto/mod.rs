@@ -0,0 +1,8 @@
+pub mod watchlist_items;
+pub use watchlist_items::{
+    AddWatchlistItemRequest, GetWatchlistResponse, RemoveWatchlistItemParams,
+    RemoveWatchlistItemResponse, WatchlistItem, WatchlistItemResponse,
+};
+
+pub mod price_alerts;
+pub use price_alerts::{GetPriceAlertsResponse, PriceAlert, PriceAlertsQueryParams};
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::models::{ResponseStatus, pagination::constants::DEFAULT_PAGE_SIZE};
+
+#[derive(Deserialize, Debug)]
+pub struct PriceAlertsQueryParams {
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+}
+
+fn default_limit() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
+
+#[derive(Serialize, Deserialize, Debug, FromRow)]
+pub struct PriceAlert {
+    pub id: uuid::Uuid,
+    pub appid: String,
+    pub name: String,
+    pub country_code: String,
+    pub currency: String,
+    pub initial_price: i32,
+    pub final_price: i32,
+    pub discount_percent: i32,
+    pub target_price: Option<i32>,
+    pub discount_threshold: Option<i32>,
+    // Owner of the watchlist entry, only one of them is set
+    pub steam_id: Option<String>,
+    pub admin: Option<String>,
+    pub webhook_status: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetPriceAlertsResponse {
+    pub status: ResponseStatus,
+    pub alerts: Vec<PriceAlert>,
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::models::ResponseStatus;
+
+// At least one of `target_price` (smallest currency unit) and `discount_threshold`
+// (percent) is required, an alert fires when either one is reached
+#[derive(Deserialize, Debug)]
+pub struct AddWatchlistItemRequest {
+    pub appid: String,
+    pub cc: Option<String>,
+    pub target_price: Option<i32>,
+    pub discount_threshold: Option<i32>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RemoveWatchlistItemParams {
+    pub cc: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, FromRow)]
+pub struct WatchlistItem {
+    pub appid: String,
+    pub name: String,
+    pub country_code: String,
+    pub target_price: Option<i32>,
+    pub discount_threshold: Option<i32>,
+    // Latest recorded price in the region, if any
+    pub currency: Option<String>,
+    pub final_price: Option<i32>,
+    pub discount_percent: Option<i32>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetWatchlistResponse {
+    pub status: ResponseStatus,
+    pub items: Vec<WatchlistItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WatchlistItemResponse {
+    pub status: ResponseStatus,
+    pub item: WatchlistItem,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RemoveWatchlistItemResponse {
+    pub status: ResponseStatus,
+    pub removed: u64,
+}
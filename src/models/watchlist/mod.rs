@@ -0,0 +1,3 @@
+pub mod dto;
+pub mod watchlist_owner;
+pub mod webhook_status;
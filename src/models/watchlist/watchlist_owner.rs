@@ -0,0 +1,28 @@
+// Watchlists belong to a tracked user (by Steam id) or to the calling admin (by username)
+#[derive(Debug, Clone)]
+pub enum WatchlistOwner {
+    User(String),
+    Admin(String),
+}
+
+impl WatchlistOwner {
+    pub fn steam_id(&self) -> Option<&str> {
+        match self {
+            WatchlistOwner::User(steam_id) => Some(steam_id),
+            WatchlistOwner::Admin(_) => None,
+        }
+    }
+
+    pub fn admin_username(&self) -> Option<&str> {
+        match self {
+            WatchlistOwner::User(_) => None,
+            WatchlistOwner::Admin(username) => Some(username),
+        }
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct WatchlistOwnerIds {
+    pub user_id: Option<uuid::Uuid>,
+    pub admin_id: Option<uuid::Uuid>,
+}
@@ -0,0 +1,17 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WebhookStatus {
+    Sent,
+    Failed,
+    // No webhook url is configured
+    Skipped,
+}
+
+impl WebhookStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookStatus::Sent => "sent",
+            WebhookStatus::Failed => "failed",
+            WebhookStatus::Skipped => "skipped",
+        }
+    }
+}
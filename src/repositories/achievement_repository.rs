@@ -0,0 +1,171 @@
+use sqlx::{Error as SqlxError, PgPool};
+use uuid::Uuid;
+
+use crate::models::achievement::{
+    achievement::AchievementModel,
+    dto::{
+        GameAchievementStats, GameCompletion, RecentAchievement, UpsertAchievementSchema,
+        UpsertUserAchievementSchema,
+    },
+    user_achievement::UserAchievementModel,
+};
+
+pub struct AchievementRepository;
+
+impl AchievementRepository {
+    pub async fn upsert_achievement(
+        pool: &PgPool,
+        body: UpsertAchievementSchema,
+    ) -> Result<AchievementModel, SqlxError> {
+        sqlx::query_as!(
+            AchievementModel,
+            "INSERT into achievements (game_id, api_name, display_name, description, icon, icon_gray, hidden)
+            values ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (game_id, api_name) DO UPDATE SET
+                display_name = EXCLUDED.display_name,
+                description = EXCLUDED.description,
+                icon = EXCLUDED.icon,
+                icon_gray = EXCLUDED.icon_gray,
+                hidden = EXCLUDED.hidden,
+                updated_at = NOW()
+            RETURNING *",
+            body.game_id,
+            body.api_name,
+            body.display_name,
+            body.description,
+            body.icon,
+            body.icon_gray,
+            body.hidden
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn get_game_achievements(
+        pool: &PgPool,
+        game_id: Uuid,
+    ) -> Result<Vec<AchievementModel>, SqlxError> {
+        sqlx::query_as!(
+            AchievementModel,
+            "SELECT * FROM achievements WHERE game_id = $1 ORDER BY display_name",
+            game_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn upsert_user_achievement(
+        pool: &PgPool,
+        body: UpsertUserAchievementSchema,
+    ) -> Result<UserAchievementModel, SqlxError> {
+        sqlx::query_as!(
+            UserAchievementModel,
+            "INSERT into user_achievements (user_id, achievement_id, unlocked_at)
+            values ($1, $2, $3)
+            ON CONFLICT (user_id, achievement_id) DO UPDATE SET unlocked_at = EXCLUDED.unlocked_at
+            RETURNING *",
+            body.user_id,
+            body.achievement_id,
+            body.unlocked_at
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn get_user_completion(
+        pool: &PgPool,
+        user_id: Uuid,
+    ) -> Result<Vec<GameCompletion>, SqlxError> {
+        // Covers games in the user's library as well as games with imported unlocks.
+        // Each unlock adds (100 - global percent) / 100 to the rarity score
+        sqlx::query_as!(
+            GameCompletion,
+            r#"SELECT g.appid, g.name,
+                COUNT(ua.id) AS "unlocked!",
+                COUNT(a.id) AS "total!",
+                ROUND(100.0 * COUNT(ua.id) / COUNT(a.id), 2)::FLOAT8 AS "completion_percentage!",
+                COALESCE(SUM((100 - a.global_percent) / 100) FILTER (WHERE ua.id IS NOT NULL), 0)::FLOAT8 AS "rarity_score!"
+            FROM achievements a
+            INNER JOIN games g ON g.id = a.game_id
+            LEFT JOIN user_achievements ua ON ua.achievement_id = a.id AND ua.user_id = $1
+            WHERE a.game_id IN (
+                SELECT game_id FROM user_games WHERE user_id = $1
+                UNION
+                SELECT a2.game_id FROM user_achievements ua2
+                INNER JOIN achievements a2 ON a2.id = ua2.achievement_id
+                WHERE ua2.user_id = $1
+            )
+            GROUP BY g.appid, g.name
+            ORDER BY 5 DESC, g.name ASC"#,
+            user_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_recent_user_achievements(
+        pool: &PgPool,
+        user_id: Uuid,
+        limit: i64,
+        rare_percent: f64,
+    ) -> Result<Vec<RecentAchievement>, SqlxError> {
+        sqlx::query_as!(
+            RecentAchievement,
+            r#"SELECT g.appid, g.name AS game_name, a.api_name, a.display_name, a.description, a.icon,
+                a.global_percent, COALESCE(a.global_percent < $3, false) AS "rare!", ua.unlocked_at
+            FROM user_achievements ua
+            INNER JOIN achievements a ON a.id = ua.achievement_id
+            INNER JOIN games g ON g.id = a.game_id
+            WHERE ua.user_id = $1
+            ORDER BY ua.unlocked_at DESC
+            LIMIT $2"#,
+            user_id,
+            limit,
+            rare_percent
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn update_global_percent(
+        pool: &PgPool,
+        game_id: Uuid,
+        api_name: &str,
+        global_percent: f64,
+    ) -> Result<u64, SqlxError> {
+        let rows = sqlx::query!(
+            "UPDATE achievements SET global_percent = $3, global_percent_updated_at = NOW()
+            WHERE game_id = $1 AND api_name = $2",
+            game_id,
+            api_name,
+            global_percent
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        Ok(rows)
+    }
+
+    pub async fn get_game_achievement_stats(
+        pool: &PgPool,
+        game_id: Uuid,
+        rare_percent: f64,
+    ) -> Result<Vec<GameAchievementStats>, SqlxError> {
+        sqlx::query_as!(
+            GameAchievementStats,
+            r#"SELECT a.api_name, a.display_name, a.description, a.icon, a.hidden, a.global_percent,
+                COALESCE(a.global_percent < $2, false) AS "rare!",
+                COUNT(ua.id) AS "tracked_unlocks!"
+            FROM achievements a
+            LEFT JOIN user_achievements ua ON ua.achievement_id = a.id
+            WHERE a.game_id = $1
+            GROUP BY a.id
+            ORDER BY a.global_percent DESC NULLS LAST, a.display_name ASC"#,
+            game_id,
+            rare_percent
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,112 @@
+use sqlx::{Error as SqlxError, PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::models::ban::{
+    ban::UserBanModel,
+    dto::{BanHistoryEntry, BanStatus, UpsertUserBanSchema},
+};
+
+pub struct BanRepository;
+
+impl BanRepository {
+    pub async fn get_user_ban(
+        executor: impl PgExecutor<'_>,
+        user_id: Uuid,
+    ) -> Result<Option<UserBanModel>, SqlxError> {
+        sqlx::query_as!(
+            UserBanModel,
+            r#"SELECT id, user_id, vac_banned, number_of_vac_bans, days_since_last_ban, number_of_game_bans,
+                community_banned, economy_ban, is_banned AS "is_banned!", created_at, updated_at
+            FROM user_bans WHERE user_id = $1"#,
+            user_id
+        )
+        .fetch_optional(executor)
+        .await
+    }
+
+    pub async fn upsert_user_ban(
+        executor: impl PgExecutor<'_>,
+        body: UpsertUserBanSchema,
+    ) -> Result<UserBanModel, SqlxError> {
+        sqlx::query_as!(
+            UserBanModel,
+            r#"INSERT into user_bans
+            (user_id, vac_banned, number_of_vac_bans, days_since_last_ban, number_of_game_bans, community_banned, economy_ban)
+            values ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (user_id) DO UPDATE SET
+                vac_banned = EXCLUDED.vac_banned,
+                number_of_vac_bans = EXCLUDED.number_of_vac_bans,
+                days_since_last_ban = EXCLUDED.days_since_last_ban,
+                number_of_game_bans = EXCLUDED.number_of_game_bans,
+                community_banned = EXCLUDED.community_banned,
+                economy_ban = EXCLUDED.economy_ban,
+                updated_at = NOW()
+            RETURNING id, user_id, vac_banned, number_of_vac_bans, days_since_last_ban, number_of_game_bans,
+                community_banned, economy_ban, is_banned AS "is_banned!", created_at, updated_at"#,
+            body.user_id,
+            body.vac_banned,
+            body.number_of_vac_bans,
+            body.days_since_last_ban,
+            body.number_of_game_bans,
+            body.community_banned,
+            body.economy_ban
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn create_ban_history(
+        executor: impl PgExecutor<'_>,
+        body: UpsertUserBanSchema,
+    ) -> Result<BanHistoryEntry, SqlxError> {
+        sqlx::query_as!(
+            BanHistoryEntry,
+            "INSERT into user_ban_history
+            (user_id, vac_banned, number_of_vac_bans, days_since_last_ban, number_of_game_bans, community_banned, economy_ban)
+            values ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING vac_banned, number_of_vac_bans, days_since_last_ban, number_of_game_bans, community_banned, economy_ban, recorded_at",
+            body.user_id,
+            body.vac_banned,
+            body.number_of_vac_bans,
+            body.days_since_last_ban,
+            body.number_of_game_bans,
+            body.community_banned,
+            body.economy_ban
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_ban_status_by_steam_id(
+        pool: &PgPool,
+        steam_id: &str,
+    ) -> Result<Option<BanStatus>, SqlxError> {
+        sqlx::query_as!(
+            BanStatus,
+            r#"SELECT b.vac_banned, b.number_of_vac_bans, b.days_since_last_ban, b.number_of_game_bans,
+                b.community_banned, b.economy_ban, b.is_banned AS "is_banned!", b.updated_at
+            FROM user_bans b
+            INNER JOIN users u ON u.id = b.user_id
+            WHERE u.steam_id = $1 AND u.deleted_at IS NULL"#,
+            steam_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn get_ban_history(
+        pool: &PgPool,
+        user_id: Uuid,
+    ) -> Result<Vec<BanHistoryEntry>, SqlxError> {
+        sqlx::query_as!(
+            BanHistoryEntry,
+            "SELECT vac_banned, number_of_vac_bans, days_since_last_ban, number_of_game_bans, community_banned, economy_ban, recorded_at
+            FROM user_ban_history
+            WHERE user_id = $1
+            ORDER BY recorded_at DESC",
+            user_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
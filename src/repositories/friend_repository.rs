@@ -0,0 +1,116 @@
+use sqlx::{Error as SqlxError, PgPool};
+use uuid::Uuid;
+
+use crate::models::{
+    friend::{
+        dto::{MutualFriend, TrackedFriend, UpsertFriendSchema},
+        friend::FriendModel,
+    },
+    user::constants::PUBLIC_VISIBILITY,
+};
+
+pub struct FriendRepository;
+
+impl FriendRepository {
+    pub async fn upsert_friend(
+        pool: &PgPool,
+        body: UpsertFriendSchema,
+    ) -> Result<FriendModel, SqlxError> {
+        sqlx::query_as!(
+            FriendModel,
+            "INSERT into user_friends (user_id, friend_steam_id, relationship, friend_since)
+            values ($1, $2, $3, $4)
+            ON CONFLICT (user_id, friend_steam_id) DO UPDATE SET
+                relationship = EXCLUDED.relationship,
+                friend_since = EXCLUDED.friend_since,
+                updated_at = NOW()
+            RETURNING *",
+            body.user_id,
+            body.friend_steam_id,
+            body.relationship,
+            body.friend_since
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn delete_removed_friends(
+        pool: &PgPool,
+        user_id: Uuid,
+        friend_steam_ids: &[String],
+    ) -> Result<u64, SqlxError> {
+        let rows = sqlx::query!(
+            "DELETE FROM user_friends WHERE user_id = $1 AND NOT (friend_steam_id = ANY($2))",
+            user_id,
+            friend_steam_ids
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        Ok(rows)
+    }
+
+    // Friends with private profiles are left out for the public, their current game included
+    pub async fn get_tracked_friends(
+        pool: &PgPool,
+        user_id: Uuid,
+        public_profiles_only: bool,
+    ) -> Result<Vec<TrackedFriend>, SqlxError> {
+        sqlx::query_as!(
+            TrackedFriend,
+            "SELECT u.steam_id, u.username, u.avatar, u.pf_url, u.current_game, u.gameid, uf.friend_since
+            FROM user_friends uf
+            INNER JOIN users u ON u.steam_id = uf.friend_steam_id AND u.deleted_at IS NULL
+            WHERE uf.user_id = $1 AND (NOT $2 OR u.visibility = $3)
+            ORDER BY u.username",
+            user_id,
+            public_profiles_only,
+            PUBLIC_VISIBILITY
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_mutual_friends(
+        pool: &PgPool,
+        user_id: Uuid,
+        other_user_id: Uuid,
+    ) -> Result<Vec<MutualFriend>, SqlxError> {
+        sqlx::query_as!(
+            MutualFriend,
+            r#"SELECT a.friend_steam_id AS steam_id, u.username AS "username?", u.avatar AS "avatar?"
+            FROM user_friends a
+            INNER JOIN user_friends b ON b.friend_steam_id = a.friend_steam_id
+            LEFT JOIN users u ON u.steam_id = a.friend_steam_id AND u.deleted_at IS NULL
+            WHERE a.user_id = $1 AND b.user_id = $2
+            ORDER BY u.username NULLS LAST, a.friend_steam_id"#,
+            user_id,
+            other_user_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_friends_playing(
+        pool: &PgPool,
+        user_id: Uuid,
+        appid: &str,
+        public_profiles_only: bool,
+    ) -> Result<Vec<TrackedFriend>, SqlxError> {
+        sqlx::query_as!(
+            TrackedFriend,
+            "SELECT u.steam_id, u.username, u.avatar, u.pf_url, u.current_game, u.gameid, uf.friend_since
+            FROM user_friends uf
+            INNER JOIN users u ON u.steam_id = uf.friend_steam_id AND u.deleted_at IS NULL
+            WHERE uf.user_id = $1 AND u.gameid = $2 AND (NOT $3 OR u.visibility = $4)
+            ORDER BY u.username",
+            user_id,
+            appid,
+            public_profiles_only,
+            PUBLIC_VISIBILITY
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
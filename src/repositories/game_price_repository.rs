@@ -0,0 +1,128 @@
+use sqlx::{Error as SqlxError, PgPool};
+
+use crate::models::game::{
+    dto::{CurrentPrice, LowestPrice, PriceSnapshot, UpsertGamePriceSchema},
+    game_price::GamePriceModel,
+};
+
+pub struct GamePriceRepository;
+
+impl GamePriceRepository {
+    pub async fn upsert_game_price(
+        pool: &PgPool,
+        body: UpsertGamePriceSchema,
+    ) -> Result<GamePriceModel, SqlxError> {
+        sqlx::query_as!(
+            GamePriceModel,
+            "INSERT INTO game_prices
+            (game_id, country_code, currency, initial_price, final_price, discount_percent)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (game_id, country_code, snapshot_date) DO UPDATE SET
+                currency = EXCLUDED.currency,
+                initial_price = EXCLUDED.initial_price,
+                final_price = EXCLUDED.final_price,
+                discount_percent = EXCLUDED.discount_percent,
+                updated_at = NOW()
+            RETURNING *",
+            body.game_id,
+            body.country_code,
+            body.currency,
+            body.initial_price,
+            body.final_price,
+            body.discount_percent
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    // The ongoing discount started on the first discounted snapshot after the last
+    // full price one
+    pub async fn get_current_prices(
+        pool: &PgPool,
+        game_id: uuid::Uuid,
+        country_code: Option<&str>,
+    ) -> Result<Vec<CurrentPrice>, SqlxError> {
+        sqlx::query_as!(
+            CurrentPrice,
+            r#"WITH latest AS (
+                SELECT DISTINCT ON (country_code) *
+                FROM game_prices
+                WHERE game_id = $1 AND ($2::TEXT IS NULL OR country_code = UPPER($2))
+                ORDER BY country_code, snapshot_date DESC
+            )
+            SELECT
+                l.country_code AS "country_code!",
+                l.currency AS "currency!",
+                l.initial_price AS "initial_price!",
+                l.final_price AS "final_price!",
+                l.discount_percent AS "discount_percent!",
+                l.discount_percent > 0 AS "on_sale!",
+                CASE WHEN l.discount_percent > 0 THEN (
+                    SELECT MIN(p.snapshot_date) FROM game_prices p
+                    WHERE p.game_id = l.game_id AND p.country_code = l.country_code
+                        AND p.snapshot_date > COALESCE((
+                            SELECT MAX(f.snapshot_date) FROM game_prices f
+                            WHERE f.game_id = l.game_id AND f.country_code = l.country_code
+                                AND f.discount_percent = 0), '-infinity'::DATE)
+                ) END AS discount_started_on,
+                lowest.final_price AS "lowest_price!",
+                l.final_price <= lowest.final_price AS "is_lowest_ever!",
+                l.updated_at AS "updated_at!"
+            FROM latest l
+            CROSS JOIN LATERAL (
+                SELECT MIN(p.final_price) AS final_price FROM game_prices p
+                WHERE p.game_id = l.game_id AND p.country_code = l.country_code
+            ) lowest
+            ORDER BY l.country_code"#,
+            game_id,
+            country_code
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_lowest_prices(
+        pool: &PgPool,
+        game_id: uuid::Uuid,
+        country_code: Option<&str>,
+    ) -> Result<Vec<LowestPrice>, SqlxError> {
+        sqlx::query_as!(
+            LowestPrice,
+            "SELECT DISTINCT ON (country_code)
+                country_code, currency, final_price, discount_percent, snapshot_date
+            FROM game_prices
+            WHERE game_id = $1 AND ($2::TEXT IS NULL OR country_code = UPPER($2))
+            ORDER BY country_code, final_price, snapshot_date",
+            game_id,
+            country_code
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_price_history(
+        pool: &PgPool,
+        game_id: uuid::Uuid,
+        country_code: Option<&str>,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+    ) -> Result<Vec<PriceSnapshot>, SqlxError> {
+        // Date bounds are inclusive on both ends
+        sqlx::query_as!(
+            PriceSnapshot,
+            "SELECT country_code, currency, initial_price, final_price, discount_percent, snapshot_date
+            FROM game_prices
+            WHERE game_id = $1
+                AND ($2::TEXT IS NULL OR country_code = UPPER($2))
+                AND ($3::DATE IS NULL OR snapshot_date >= $3)
+                AND ($4::DATE IS NULL OR snapshot_date <= $4)
+            ORDER BY country_code, snapshot_date",
+            game_id,
+            country_code,
+            from,
+            to
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -1,10 +1,57 @@
-use sqlx::{Error as SqlxError, PgPool};
+use sqlx::{Error as SqlxError, PgExecutor, PgPool, Postgres, QueryBuilder};
 
 use crate::models::game::{
-    dto::{BindUserToGameSchema, CreateGameSchema},
-    game::GameModel,
+    dto::{
+        BindUserToGameSchema, CatalogGame, CreateGameSchema, CreateGameStubSchema, GameDlc,
+        GamePlaytime, UpsertPlaytimeSnapshotSchema, UpsertUserGameSchema, UserGame, UserGamesSort,
+    },
+    game::{GameModel, GameTaxonomy},
+    game_change::GameChange,
+    game_movie::GameMovie,
+    game_review::GameReviewModel,
+    pagination::game_filters::{GameFilters, GameSortField},
+    playtime_snapshot::PlaytimeSnapshotModel,
+    sync_status::GameSyncStatus,
     user_games::UserGamesModel,
 };
+use crate::models::pagination::{Cursor, CursorRow, SortOrder};
+use crate::repositories::{keyset, like_pattern};
+
+// Games plus the aggregates the catalog filters and sorts on, soft deleted users
+// do not count as owners. The catalog shows and filters on the latest US price, so amounts
+// share a currency.
+const CATALOG_QUERY: &str = "(
+    SELECT g.*,
+        ARRAY_REMOVE(ARRAY[
+            CASE WHEN g.platform_windows THEN 'windows' END,
+            CASE WHEN g.platform_mac THEN 'mac' END,
+            CASE WHEN g.platform_linux THEN 'linux' END
+        ], NULL) AS platforms,
+        ARRAY(SELECT ge.description FROM game_genres gg
+            INNER JOIN genres ge ON ge.id = gg.genre_id
+            WHERE gg.game_id = g.id ORDER BY ge.description) AS genres,
+        ARRAY(SELECT c.description FROM game_categories gc
+            INNER JOIN categories c ON c.id = gc.category_id
+            WHERE gc.game_id = g.id ORDER BY c.description) AS categories,
+        COALESCE(stats.owners, 0) AS owners,
+        COALESCE(stats.players, 0) AS players,
+        us.final_price AS us_price,
+        us.currency AS us_currency
+    FROM games g
+    LEFT JOIN LATERAL (
+        SELECT gp.final_price, gp.currency FROM game_prices gp
+        WHERE gp.game_id = g.id AND gp.country_code = 'US'
+        ORDER BY gp.snapshot_date DESC LIMIT 1
+    ) us ON true
+    LEFT JOIN (
+        SELECT ug.game_id, COUNT(*) AS owners,
+            COUNT(*) FILTER (WHERE ug.playtime_forever > 0) AS players
+        FROM user_games ug
+        INNER JOIN users u ON u.id = ug.user_id
+        WHERE u.deleted_at IS NULL
+        GROUP BY ug.game_id
+    ) stats ON stats.game_id = g.id
+) catalog";
 
 pub struct GameRepository;
 
@@ -19,18 +66,253 @@ impl GameRepository {
     }
 
     pub async fn create_game(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         body: CreateGameSchema,
     ) -> Result<GameModel, SqlxError> {
         sqlx::query_as!(
-        GameModel,
-        "INSERT into games (appid,name,short_description,header_image,screenshots) values ($1,$2,$3,$4,$5) returning *",
-        body.appid,
-        body.name,
-        body.short_description,
-        body.header_image,
-        &body.screenshots
-    ).fetch_one(pool).await
+            GameModel,
+            "INSERT into games
+            (appid, name, short_description, header_image, screenshots, is_free, price_final,
+                price_currency, release_date, platform_windows, platform_mac, platform_linux,
+                coming_soon, required_age, metacritic_score, metacritic_url, website,
+                supported_languages, background, last_synced_at, sync_attempted_at, sync_status,
+                app_type)
+            values ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17,
+                $18, $19, NOW(), NOW(), $20, $21)
+            returning *",
+            body.appid,
+            body.name,
+            body.short_description,
+            body.header_image,
+            &body.screenshots,
+            body.is_free,
+            body.price_final,
+            body.price_currency,
+            body.release_date,
+            body.platform_windows,
+            body.platform_mac,
+            body.platform_linux,
+            body.coming_soon,
+            body.required_age,
+            body.metacritic_score,
+            body.metacritic_url,
+            body.website,
+            &body.supported_languages,
+            body.background,
+            GameSyncStatus::Synced.as_str(),
+            body.app_type
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn update_game(
+        executor: impl PgExecutor<'_>,
+        game_id: &uuid::Uuid,
+        body: CreateGameSchema,
+    ) -> Result<GameModel, SqlxError> {
+        sqlx::query_as!(
+            GameModel,
+            "UPDATE games SET
+                name = $1,
+                short_description = $2,
+                header_image = $3,
+                screenshots = $4,
+                is_free = $5,
+                price_final = $6,
+                price_currency = $7,
+                release_date = $8,
+                platform_windows = $9,
+                platform_mac = $10,
+                platform_linux = $11,
+                coming_soon = $12,
+                required_age = $13,
+                metacritic_score = $14,
+                metacritic_url = $15,
+                website = $16,
+                supported_languages = $17,
+                background = $18,
+                last_synced_at = NOW(),
+                sync_attempted_at = NOW(),
+                sync_status = $19,
+                sync_error = NULL,
+                app_type = $20
+            WHERE id = $21
+            RETURNING *",
+            body.name,
+            body.short_description,
+            body.header_image,
+            &body.screenshots,
+            body.is_free,
+            body.price_final,
+            body.price_currency,
+            body.release_date,
+            body.platform_windows,
+            body.platform_mac,
+            body.platform_linux,
+            body.coming_soon,
+            body.required_age,
+            body.metacritic_score,
+            body.metacritic_url,
+            body.website,
+            &body.supported_languages,
+            body.background,
+            GameSyncStatus::Synced.as_str(),
+            body.app_type,
+            game_id
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn mark_sync_failed(
+        pool: &PgPool,
+        game_id: &uuid::Uuid,
+        status: GameSyncStatus,
+        error: &str,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            "UPDATE games SET sync_attempted_at = NOW(), sync_status = $1, sync_error = $2
+            WHERE id = $3",
+            status.as_str(),
+            error,
+            game_id
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // Never attempted games (library stubs) come first, then the longest unsynced ones
+    pub async fn get_stale_appids(
+        pool: &PgPool,
+        older_than_days: i32,
+        limit: i64,
+    ) -> Result<Vec<String>, SqlxError> {
+        sqlx::query_scalar!(
+            "SELECT appid FROM games
+            WHERE sync_attempted_at IS NULL
+                OR sync_attempted_at < NOW() - make_interval(days => $1)
+            ORDER BY sync_attempted_at NULLS FIRST
+            LIMIT $2",
+            older_than_days,
+            limit
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    // Lookup links and movies are replaced on every sync
+    pub async fn clear_game_metadata(
+        executor: impl PgExecutor<'_>,
+        game_id: &uuid::Uuid,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            "WITH genres AS (DELETE FROM game_genres WHERE game_id = $1),
+                categories AS (DELETE FROM game_categories WHERE game_id = $1),
+                developers AS (DELETE FROM game_developers WHERE game_id = $1),
+                publishers AS (DELETE FROM game_publishers WHERE game_id = $1)
+            DELETE FROM game_movies WHERE game_id = $1",
+            game_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_game_change(
+        executor: impl PgExecutor<'_>,
+        game_id: &uuid::Uuid,
+        field: &str,
+        old_value: serde_json::Value,
+        new_value: serde_json::Value,
+    ) -> Result<GameChange, SqlxError> {
+        sqlx::query_as!(
+            GameChange,
+            "INSERT INTO game_changes (game_id, field, old_value, new_value)
+            VALUES ($1, $2, $3, $4)
+            RETURNING field, old_value, new_value, created_at",
+            game_id,
+            field,
+            old_value,
+            new_value
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_game_changes(
+        pool: &PgPool,
+        game_id: &uuid::Uuid,
+        limit: i64,
+    ) -> Result<Vec<GameChange>, SqlxError> {
+        sqlx::query_as!(
+            GameChange,
+            "SELECT field, old_value, new_value, created_at FROM game_changes
+            WHERE game_id = $1
+            ORDER BY created_at DESC, field
+            LIMIT $2",
+            game_id,
+            limit
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn bind_game_relation(
+        executor: impl PgExecutor<'_>,
+        parent_appid: &str,
+        child_appid: &str,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            "INSERT INTO game_relations (parent_appid, child_appid) VALUES ($1, $2)
+            ON CONFLICT DO NOTHING",
+            parent_appid,
+            child_appid
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_parent_appid(
+        pool: &PgPool,
+        child_appid: &str,
+    ) -> Result<Option<String>, SqlxError> {
+        sqlx::query_scalar!(
+            "SELECT parent_appid FROM game_relations WHERE child_appid = $1
+            ORDER BY created_at
+            LIMIT 1",
+            child_appid
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    // Stored children first, newest release first, then the unknown ones by appid
+    pub async fn get_game_children(
+        pool: &PgPool,
+        parent_appid: &str,
+        app_type: Option<&str>,
+    ) -> Result<Vec<GameDlc>, SqlxError> {
+        sqlx::query_as!(
+            GameDlc,
+            r#"SELECT r.child_appid AS appid, g.app_type AS "app_type?", g.name AS "name?",
+                g.header_image, g.is_free AS "is_free?", g.price_final, g.price_currency,
+                g.release_date
+            FROM game_relations r
+            LEFT JOIN games g ON g.appid = r.child_appid
+            WHERE r.parent_appid = $1
+                AND ($2::TEXT IS NULL OR g.app_type = LOWER($2))
+            ORDER BY g.id IS NULL, g.release_date DESC NULLS LAST, g.name, r.child_appid"#,
+            parent_appid,
+            app_type
+        )
+        .fetch_all(pool)
+        .await
     }
 
     pub async fn get_game_by_appid(pool: &PgPool, appid: &str) -> Result<GameModel, SqlxError> {
@@ -45,6 +327,85 @@ impl GameRepository {
             .await
     }
 
+    // Library stubs wait for their first sync unless watchlisted. Watchlisted games come
+    // first, then the ones imported longest ago.
+    pub async fn get_price_stale_appids(
+        pool: &PgPool,
+        older_than_hours: i32,
+        limit: i64,
+    ) -> Result<Vec<String>, SqlxError> {
+        sqlx::query_scalar!(
+            "SELECT g.appid FROM games g
+            WHERE (g.sync_status = $1 OR EXISTS(SELECT 1 FROM watchlists w WHERE w.game_id = g.id))
+                AND (g.prices_imported_at IS NULL
+                    OR g.prices_imported_at < NOW() - make_interval(hours => $2))
+            ORDER BY EXISTS(SELECT 1 FROM watchlists w WHERE w.game_id = g.id) DESC,
+                g.prices_imported_at NULLS FIRST
+            LIMIT $3",
+            GameSyncStatus::Synced.as_str(),
+            older_than_hours,
+            limit
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn mark_prices_imported(
+        pool: &PgPool,
+        game_id: &uuid::Uuid,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!("UPDATE games SET prices_imported_at = NOW() WHERE id = $1", game_id)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_all_appids(pool: &PgPool) -> Result<Vec<String>, SqlxError> {
+        sqlx::query_scalar!("SELECT appid FROM games ORDER BY appid")
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn update_game_price(
+        pool: &PgPool,
+        game_id: &uuid::Uuid,
+        price_final: i32,
+        price_currency: &str,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            "UPDATE games SET price_final = $1, price_currency = $2 WHERE id = $3",
+            price_final,
+            price_currency,
+            game_id
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn update_game_reviews(
+        executor: impl PgExecutor<'_>,
+        game_id: &uuid::Uuid,
+        review: &GameReviewModel,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            "UPDATE games SET review_score = $1, review_score_desc = $2,
+                review_total_positive = $3, review_total_negative = $4
+            WHERE id = $5",
+            review.review_score,
+            review.review_score_desc,
+            review.total_positive,
+            review.total_negative,
+            game_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn bind_user_to_game(
         pool: &PgPool,
         body: BindUserToGameSchema,
@@ -90,4 +451,461 @@ impl GameRepository {
 
         Ok(existing_query.unwrap_or(false))
     }
+
+    pub async fn create_game_stub(
+        pool: &PgPool,
+        body: CreateGameStubSchema,
+    ) -> Result<GameModel, SqlxError> {
+        // The no-op update makes RETURNING yield the row when it already exists
+        sqlx::query_as!(
+            GameModel,
+            "INSERT into games (appid, name) values ($1, $2)
+            ON CONFLICT (appid) DO UPDATE SET appid = EXCLUDED.appid
+            RETURNING *",
+            body.appid,
+            body.name
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn upsert_user_game(
+        pool: &PgPool,
+        body: UpsertUserGameSchema,
+    ) -> Result<UserGamesModel, SqlxError> {
+        sqlx::query_as!(
+            UserGamesModel,
+            "INSERT into user_games (user_id, game_id, playtime_forever, playtime_2weeks, last_played_at)
+            values ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id, game_id) DO UPDATE SET
+                playtime_forever = EXCLUDED.playtime_forever,
+                playtime_2weeks = EXCLUDED.playtime_2weeks,
+                last_played_at = COALESCE(EXCLUDED.last_played_at, user_games.last_played_at),
+                updated_at = NOW()
+            RETURNING *",
+            body.user_id,
+            body.game_id,
+            body.playtime_forever,
+            body.playtime_2weeks,
+            body.last_played_at
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn get_user_games(
+        pool: &PgPool,
+        user_id: uuid::Uuid,
+        sort: UserGamesSort,
+    ) -> Result<Vec<UserGame>, SqlxError> {
+        sqlx::query_as!(
+            UserGame,
+            "SELECT g.appid, g.name, g.header_image, ug.playtime_forever, ug.playtime_2weeks, ug.last_played_at
+            FROM user_games ug
+            INNER JOIN games g ON g.id = ug.game_id
+            WHERE ug.user_id = $1
+            ORDER BY
+                CASE WHEN $2 = 'recent_playtime' THEN ug.playtime_2weeks END DESC,
+                CASE WHEN $2 = 'last_played' THEN ug.last_played_at END DESC NULLS LAST,
+                CASE WHEN $2 = 'name' THEN g.name END ASC,
+                ug.playtime_forever DESC,
+                g.name ASC",
+            user_id,
+            sort.as_str()
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_recent_user_games(
+        pool: &PgPool,
+        user_id: uuid::Uuid,
+    ) -> Result<Vec<UserGame>, SqlxError> {
+        sqlx::query_as!(
+            UserGame,
+            "SELECT g.appid, g.name, g.header_image, ug.playtime_forever, ug.playtime_2weeks, ug.last_played_at
+            FROM user_games ug
+            INNER JOIN games g ON g.id = ug.game_id
+            WHERE ug.user_id = $1 AND ug.playtime_2weeks > 0
+            ORDER BY ug.playtime_2weeks DESC, g.name ASC",
+            user_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn reset_recent_playtime(
+        pool: &PgPool,
+        user_id: uuid::Uuid,
+        played_game_ids: &[uuid::Uuid],
+    ) -> Result<u64, SqlxError> {
+        let rows = sqlx::query!(
+            "UPDATE user_games SET playtime_2weeks = 0, updated_at = NOW()
+            WHERE user_id = $1 AND playtime_2weeks > 0 AND NOT (game_id = ANY($2))",
+            user_id,
+            played_game_ids
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        Ok(rows)
+    }
+
+    pub async fn upsert_playtime_snapshot(
+        pool: &PgPool,
+        body: UpsertPlaytimeSnapshotSchema,
+    ) -> Result<PlaytimeSnapshotModel, SqlxError> {
+        sqlx::query_as!(
+            PlaytimeSnapshotModel,
+            "INSERT into user_game_playtime_snapshots (user_id, game_id, playtime_forever, playtime_2weeks)
+            values ($1, $2, $3, $4)
+            ON CONFLICT (user_id, game_id, snapshot_date) DO UPDATE SET
+                playtime_forever = EXCLUDED.playtime_forever,
+                playtime_2weeks = EXCLUDED.playtime_2weeks
+            RETURNING *",
+            body.user_id,
+            body.game_id,
+            body.playtime_forever,
+            body.playtime_2weeks
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn get_playtime_totals(
+        pool: &PgPool,
+        user_id: uuid::Uuid,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<GamePlaytime>, SqlxError> {
+        // The last snapshot before the window is the baseline, falling back to
+        // the first snapshot inside it for games that were added mid-window
+        sqlx::query_as!(
+            GamePlaytime,
+            r#"WITH window_snapshots AS (
+                SELECT game_id, MIN(playtime_forever) AS first_playtime, MAX(playtime_forever) AS last_playtime
+                FROM user_game_playtime_snapshots
+                WHERE user_id = $1 AND snapshot_date BETWEEN $2 AND $3
+                GROUP BY game_id
+            ),
+            baseline AS (
+                SELECT DISTINCT ON (game_id) game_id, playtime_forever
+                FROM user_game_playtime_snapshots
+                WHERE user_id = $1 AND snapshot_date < $2
+                ORDER BY game_id, snapshot_date DESC
+            )
+            SELECT g.appid, g.name,
+                (w.last_playtime - COALESCE(b.playtime_forever, w.first_playtime))::BIGINT AS "minutes_played!"
+            FROM window_snapshots w
+            INNER JOIN games g ON g.id = w.game_id
+            LEFT JOIN baseline b ON b.game_id = w.game_id
+            WHERE w.last_playtime - COALESCE(b.playtime_forever, w.first_playtime) > 0
+            ORDER BY 3 DESC, g.name ASC"#,
+            user_id,
+            from,
+            to
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn upsert_genre(
+        executor: impl PgExecutor<'_>,
+        steam_genre_id: &str,
+        description: &str,
+    ) -> Result<uuid::Uuid, SqlxError> {
+        sqlx::query_scalar!(
+            "INSERT INTO genres (steam_genre_id, description)
+            VALUES ($1, $2)
+            ON CONFLICT (steam_genre_id) DO UPDATE SET description = EXCLUDED.description
+            RETURNING id",
+            steam_genre_id,
+            description
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn bind_game_genre(
+        executor: impl PgExecutor<'_>,
+        game_id: &uuid::Uuid,
+        genre_id: &uuid::Uuid,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            "INSERT INTO game_genres (game_id, genre_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+            game_id,
+            genre_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn upsert_category(
+        executor: impl PgExecutor<'_>,
+        steam_category_id: i32,
+        description: &str,
+    ) -> Result<uuid::Uuid, SqlxError> {
+        sqlx::query_scalar!(
+            "INSERT INTO categories (steam_category_id, description)
+            VALUES ($1, $2)
+            ON CONFLICT (steam_category_id) DO UPDATE SET description = EXCLUDED.description
+            RETURNING id",
+            steam_category_id,
+            description
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn bind_game_category(
+        executor: impl PgExecutor<'_>,
+        game_id: &uuid::Uuid,
+        category_id: &uuid::Uuid,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            "INSERT INTO game_categories (game_id, category_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+            game_id,
+            category_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn upsert_developer(
+        executor: impl PgExecutor<'_>,
+        name: &str,
+    ) -> Result<uuid::Uuid, SqlxError> {
+        sqlx::query_scalar!(
+            "INSERT INTO developers (name) VALUES ($1)
+            ON CONFLICT (name) DO UPDATE SET name = EXCLUDED.name
+            RETURNING id",
+            name
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn bind_game_developer(
+        executor: impl PgExecutor<'_>,
+        game_id: &uuid::Uuid,
+        developer_id: &uuid::Uuid,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            "INSERT INTO game_developers (game_id, developer_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+            game_id,
+            developer_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn upsert_publisher(
+        executor: impl PgExecutor<'_>,
+        name: &str,
+    ) -> Result<uuid::Uuid, SqlxError> {
+        sqlx::query_scalar!(
+            "INSERT INTO publishers (name) VALUES ($1)
+            ON CONFLICT (name) DO UPDATE SET name = EXCLUDED.name
+            RETURNING id",
+            name
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn bind_game_publisher(
+        executor: impl PgExecutor<'_>,
+        game_id: &uuid::Uuid,
+        publisher_id: &uuid::Uuid,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            "INSERT INTO game_publishers (game_id, publisher_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+            game_id,
+            publisher_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn upsert_game_movie(
+        executor: impl PgExecutor<'_>,
+        game_id: &uuid::Uuid,
+        movie: &GameMovie,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            "INSERT INTO game_movies
+            (game_id, steam_movie_id, name, thumbnail, webm_480, webm_max, mp4_480, mp4_max, highlight)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (game_id, steam_movie_id) DO UPDATE SET
+                name = EXCLUDED.name,
+                thumbnail = EXCLUDED.thumbnail,
+                webm_480 = EXCLUDED.webm_480,
+                webm_max = EXCLUDED.webm_max,
+                mp4_480 = EXCLUDED.mp4_480,
+                mp4_max = EXCLUDED.mp4_max,
+                highlight = EXCLUDED.highlight",
+            game_id,
+            movie.steam_movie_id,
+            movie.name,
+            movie.thumbnail,
+            movie.webm_480,
+            movie.webm_max,
+            movie.mp4_480,
+            movie.mp4_max,
+            movie.highlight
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_game_taxonomy(
+        executor: impl PgExecutor<'_>,
+        game_id: &uuid::Uuid,
+    ) -> Result<GameTaxonomy, SqlxError> {
+        sqlx::query_as!(
+            GameTaxonomy,
+            r#"SELECT
+                ARRAY(SELECT ge.description FROM game_genres gg
+                    INNER JOIN genres ge ON ge.id = gg.genre_id
+                    WHERE gg.game_id = $1 ORDER BY ge.description) AS "genres!",
+                ARRAY(SELECT c.description FROM game_categories gc
+                    INNER JOIN categories c ON c.id = gc.category_id
+                    WHERE gc.game_id = $1 ORDER BY c.description) AS "categories!",
+                ARRAY(SELECT d.name FROM game_developers gd
+                    INNER JOIN developers d ON d.id = gd.developer_id
+                    WHERE gd.game_id = $1 ORDER BY d.name) AS "developers!",
+                ARRAY(SELECT p.name FROM game_publishers gp
+                    INNER JOIN publishers p ON p.id = gp.publisher_id
+                    WHERE gp.game_id = $1 ORDER BY p.name) AS "publishers!""#,
+            game_id
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_game_movies(
+        executor: impl PgExecutor<'_>,
+        game_id: &uuid::Uuid,
+    ) -> Result<Vec<GameMovie>, SqlxError> {
+        sqlx::query_as!(
+            GameMovie,
+            "SELECT steam_movie_id, name, thumbnail, webm_480, webm_max, mp4_480, mp4_max, highlight
+            FROM game_movies
+            WHERE game_id = $1
+            ORDER BY highlight DESC, steam_movie_id",
+            game_id
+        )
+        .fetch_all(executor)
+        .await
+    }
+
+    fn push_game_filters(builder: &mut QueryBuilder<'_, Postgres>, filters: &GameFilters) {
+        builder.push(" WHERE 1 = 1");
+
+        if let Some(name) = &filters.name {
+            builder
+                .push(" AND name ILIKE ")
+                .push_bind(like_pattern::contains(name))
+                .push(" ESCAPE '\\'");
+        }
+        if let Some(genre) = &filters.genre {
+            builder
+                .push(" AND EXISTS(SELECT 1 FROM game_genres gg INNER JOIN genres ge ON ge.id = gg.genre_id WHERE gg.game_id = catalog.id AND LOWER(ge.description) = LOWER(")
+                .push_bind(genre.clone())
+                .push("))");
+        }
+        if let Some(category) = &filters.category {
+            builder
+                .push(" AND EXISTS(SELECT 1 FROM game_categories gc INNER JOIN categories c ON c.id = gc.category_id WHERE gc.game_id = catalog.id AND LOWER(c.description) = LOWER(")
+                .push_bind(category.clone())
+                .push("))");
+        }
+        if let Some(platform) = filters.platform {
+            builder.push(" AND ").push(platform.as_column());
+        }
+        if let Some(app_type) = &filters.app_type {
+            builder
+                .push(" AND app_type = LOWER(")
+                .push_bind(app_type.clone())
+                .push(")");
+        }
+        if let Some(min_price) = filters.min_price {
+            builder.push(" AND us_price >= ").push_bind(min_price);
+        }
+        if let Some(max_price) = filters.max_price {
+            builder.push(" AND us_price <= ").push_bind(max_price);
+        }
+        if let Some(free) = filters.free {
+            builder.push(" AND is_free = ").push_bind(free);
+        }
+        if let Some(release_year) = filters.release_year {
+            builder
+                .push(" AND EXTRACT(YEAR FROM release_date)::INTEGER = ")
+                .push_bind(release_year);
+        }
+        if let Some(min_owners) = filters.min_owners {
+            builder.push(" AND owners >= ").push_bind(min_owners);
+        }
+        if let Some(min_players) = filters.min_players {
+            builder.push(" AND players >= ").push_bind(min_players);
+        }
+        if let Some(min_review_score) = filters.min_review_score {
+            builder.push(" AND review_score >= ").push_bind(min_review_score);
+        }
+    }
+
+    pub async fn get_games_paginated(
+        pool: &PgPool,
+        filters: &GameFilters,
+        sort: GameSortField,
+        order: SortOrder,
+        cursor: Option<&Cursor>,
+        limit: i64,
+    ) -> Result<Vec<CursorRow<CatalogGame>>, SqlxError> {
+        let sort_expression = sort.as_sort_expression();
+
+        let mut builder = QueryBuilder::<Postgres>::new(
+            "SELECT appid, app_type, name, short_description, header_image, is_free,
+                us_price AS price_final, us_currency AS price_currency, release_date, platforms, genres, categories, owners, players,
+                review_score, review_score_desc, ",
+        );
+        builder
+            .push(sort_expression)
+            .push("::TEXT AS cursor_value, id AS cursor_id FROM ")
+            .push(CATALOG_QUERY);
+        Self::push_game_filters(&mut builder, filters);
+
+        // Sort expression and direction come from whitelisted enums, never from raw input
+        keyset::push_keyset_filter(
+            &mut builder,
+            sort_expression,
+            sort.as_sql_type(),
+            "id",
+            order,
+            cursor,
+        );
+        keyset::push_keyset_order(&mut builder, sort_expression, "id", order, cursor);
+        builder.push(" LIMIT ").push_bind(limit + 1);
+
+        builder.build_query_as().fetch_all(pool).await
+    }
+
+    pub async fn count_games(pool: &PgPool, filters: &GameFilters) -> Result<i64, SqlxError> {
+        let mut builder = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM ");
+        builder.push(CATALOG_QUERY);
+        Self::push_game_filters(&mut builder, filters);
+
+        builder.build_query_scalar::<i64>().fetch_one(pool).await
+    }
 }
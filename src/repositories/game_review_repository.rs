@@ -0,0 +1,62 @@
+use sqlx::{Error as SqlxError, PgExecutor, PgPool};
+
+use crate::models::game::{
+    dto::{ReviewSnapshot, UpsertGameReviewSchema},
+    game_review::GameReviewModel,
+};
+
+pub struct GameReviewRepository;
+
+impl GameReviewRepository {
+    pub async fn upsert_game_review(
+        executor: impl PgExecutor<'_>,
+        body: UpsertGameReviewSchema,
+    ) -> Result<GameReviewModel, SqlxError> {
+        sqlx::query_as!(
+            GameReviewModel,
+            "INSERT INTO game_reviews
+            (game_id, review_score, review_score_desc, total_positive, total_negative, total_reviews)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (game_id, snapshot_date) DO UPDATE SET
+                review_score = EXCLUDED.review_score,
+                review_score_desc = EXCLUDED.review_score_desc,
+                total_positive = EXCLUDED.total_positive,
+                total_negative = EXCLUDED.total_negative,
+                total_reviews = EXCLUDED.total_reviews,
+                updated_at = NOW()
+            RETURNING *",
+            body.game_id,
+            body.review_score,
+            body.review_score_desc,
+            body.total_positive,
+            body.total_negative,
+            body.total_reviews
+        )
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_review_history(
+        pool: &PgPool,
+        game_id: uuid::Uuid,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+    ) -> Result<Vec<ReviewSnapshot>, SqlxError> {
+        // Date bounds are inclusive on both ends
+        sqlx::query_as!(
+            ReviewSnapshot,
+            "SELECT review_score, review_score_desc, total_positive, total_negative, total_reviews,
+                snapshot_date
+            FROM game_reviews
+            WHERE game_id = $1
+                AND ($2::DATE IS NULL OR snapshot_date >= $2)
+                AND ($3::DATE IS NULL OR snapshot_date <= $3)
+            ORDER BY snapshot_date",
+            game_id,
+            from,
+            to
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,110 @@
+use sqlx::{Error as SqlxError, PgPool};
+use uuid::Uuid;
+
+use crate::models::{
+    group::{
+        dto::{GroupMember, UserGroup},
+        group::GroupModel,
+    },
+    user::constants::PUBLIC_VISIBILITY,
+};
+
+pub struct GroupRepository;
+
+impl GroupRepository {
+    pub async fn upsert_group(pool: &PgPool, gid: &str) -> Result<GroupModel, SqlxError> {
+        sqlx::query_as!(
+            GroupModel,
+            "INSERT into groups (gid) values ($1)
+            ON CONFLICT (gid) DO UPDATE SET updated_at = NOW()
+            RETURNING *",
+            gid
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn bind_user_to_group(
+        pool: &PgPool,
+        user_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            "INSERT into user_groups (user_id, group_id) values ($1, $2)
+            ON CONFLICT (user_id, group_id) DO NOTHING",
+            user_id,
+            group_id
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_left_groups(
+        pool: &PgPool,
+        user_id: Uuid,
+        group_ids: &[Uuid],
+    ) -> Result<u64, SqlxError> {
+        let rows = sqlx::query!(
+            "DELETE FROM user_groups WHERE user_id = $1 AND NOT (group_id = ANY($2))",
+            user_id,
+            group_ids
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        Ok(rows)
+    }
+
+    pub async fn check_if_group_exists(pool: &PgPool, gid: &str) -> Result<bool, SqlxError> {
+        let existing_query =
+            sqlx::query_scalar!("SELECT EXISTS(SELECT 1 FROM groups WHERE gid = $1)", gid)
+                .fetch_one(pool)
+                .await?;
+
+        Ok(existing_query.unwrap_or(false))
+    }
+
+    pub async fn get_user_groups(
+        pool: &PgPool,
+        user_id: Uuid,
+    ) -> Result<Vec<UserGroup>, SqlxError> {
+        sqlx::query_as!(
+            UserGroup,
+            r#"SELECT g.gid,
+                (SELECT COUNT(*) FROM user_groups m WHERE m.group_id = g.id) AS "tracked_members!",
+                ug.created_at AS tracked_since
+            FROM user_groups ug
+            INNER JOIN groups g ON g.id = ug.group_id
+            WHERE ug.user_id = $1
+            ORDER BY g.gid"#,
+            user_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_group_members(
+        pool: &PgPool,
+        gid: &str,
+        public_profiles_only: bool,
+    ) -> Result<Vec<GroupMember>, SqlxError> {
+        sqlx::query_as!(
+            GroupMember,
+            "SELECT u.steam_id, u.username, u.avatar, u.pf_url, u.current_game
+            FROM user_groups ug
+            INNER JOIN groups g ON g.id = ug.group_id
+            INNER JOIN users u ON u.id = ug.user_id
+            WHERE g.gid = $1 AND u.deleted_at IS NULL
+                AND (NOT $2 OR u.visibility = $3)
+            ORDER BY u.username",
+            gid,
+            public_profiles_only,
+            PUBLIC_VISIBILITY
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -1,6 +1,12 @@
-use sqlx::{Error as SqlxError, PgPool};
+use sqlx::{Error as SqlxError, PgPool, Postgres, QueryBuilder};
+use uuid::Uuid;
 
-use crate::models::inventory_items::{dto::CreateItemSchema, inventory_items::InventoryItemModel};
+use crate::models::inventory_items::{
+    dto::{CreateItemSchema, InventoryItem},
+    inventory_items::InventoryItemModel,
+};
+use crate::models::pagination::{Cursor, CursorRow, SortOrder};
+use crate::repositories::keyset;
 
 pub struct InventoryItemsRepository;
 
@@ -44,4 +50,60 @@ impl InventoryItemsRepository {
 
         Ok(exists.unwrap_or(false))
     }
+
+    fn push_user_items_filters(
+        builder: &mut QueryBuilder<'_, Postgres>,
+        user_id: Uuid,
+        app_id: Option<&str>,
+    ) {
+        builder.push(" WHERE i.user_id = ").push_bind(user_id);
+
+        if let Some(app_id) = app_id {
+            builder
+                .push(" AND ii.app_id = ")
+                .push_bind(app_id.to_string());
+        }
+    }
+
+    pub async fn get_user_items_paginated(
+        pool: &PgPool,
+        user_id: Uuid,
+        app_id: Option<&str>,
+        cursor: Option<&Cursor>,
+        limit: i64,
+    ) -> Result<Vec<CursorRow<InventoryItem>>, SqlxError> {
+        let mut builder = QueryBuilder::<Postgres>::new(
+            "SELECT ii.app_id, ii.classid, ii.icon, ii.name, ii.color, ii.item_type, ii.description,
+                ii.name AS cursor_value, ii.id AS cursor_id
+            FROM inventory_items ii
+            INNER JOIN inventories i ON i.id = ii.inventory_id",
+        );
+        Self::push_user_items_filters(&mut builder, user_id, app_id);
+        keyset::push_keyset_filter(
+            &mut builder,
+            "ii.name",
+            "TEXT",
+            "ii.id",
+            SortOrder::Asc,
+            cursor,
+        );
+        keyset::push_keyset_order(&mut builder, "ii.name", "ii.id", SortOrder::Asc, cursor);
+        builder.push(" LIMIT ").push_bind(limit + 1);
+
+        builder.build_query_as().fetch_all(pool).await
+    }
+
+    pub async fn count_user_items(
+        pool: &PgPool,
+        user_id: Uuid,
+        app_id: Option<&str>,
+    ) -> Result<i64, SqlxError> {
+        let mut builder = QueryBuilder::<Postgres>::new(
+            "SELECT COUNT(*) FROM inventory_items ii
+            INNER JOIN inventories i ON i.id = ii.inventory_id",
+        );
+        Self::push_user_items_filters(&mut builder, user_id, app_id);
+
+        builder.build_query_scalar::<i64>().fetch_one(pool).await
+    }
 }
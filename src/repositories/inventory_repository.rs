@@ -14,7 +14,7 @@ impl InventoryRepository {
             SELECT 1 
             FROM users u
             INNER JOIN inventories i ON u.id = i.user_id
-            WHERE u.steam_id = $1
+            WHERE u.steam_id = $1 AND u.deleted_at IS NULL
         );",
             steam_id
         )
@@ -31,7 +31,7 @@ impl InventoryRepository {
         sqlx::query_as!(
             InventoryModel,
             "INSERT into inventories (user_id) 
-            SELECT id FROM users WHERE steam_id = $1 
+            SELECT id FROM users WHERE steam_id = $1 AND deleted_at IS NULL
             RETURNING *",
             body.steam_id
         )
@@ -47,7 +47,7 @@ impl InventoryRepository {
             "SELECT i.id 
             FROM inventories i
             INNER JOIN users u ON u.id = i.user_id
-            WHERE u.steam_id = $1",
+            WHERE u.steam_id = $1 AND u.deleted_at IS NULL",
             steam_id
         )
         .fetch_optional(pool)
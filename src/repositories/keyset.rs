@@ -0,0 +1,64 @@
+use sqlx::{Postgres, QueryBuilder};
+
+use crate::models::pagination::{Cursor, CursorDirection, SortOrder};
+
+// Keeps only rows after (or before, for `prev` cursors) the cursor position.
+// `sort_expression` must never contain user input.
+pub fn push_keyset_filter(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    sort_expression: &str,
+    sql_type: &str,
+    id_column: &str,
+    order: SortOrder,
+    cursor: Option<&Cursor>,
+) {
+    let Some(cursor) = cursor else {
+        return;
+    };
+
+    let forward = cursor.direction == CursorDirection::Next;
+    let operator = if forward == (order == SortOrder::Asc) {
+        ">"
+    } else {
+        "<"
+    };
+
+    builder
+        .push(" AND (")
+        .push(sort_expression)
+        .push(", ")
+        .push(id_column)
+        .push(") ")
+        .push(operator)
+        .push(" (CAST(")
+        .push_bind(cursor.value.clone())
+        .push(" AS ")
+        .push(sql_type)
+        .push("), ")
+        .push_bind(cursor.id)
+        .push(")");
+}
+
+// `prev` cursors walk the index backwards, CursorPage restores the order
+pub fn push_keyset_order(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    sort_expression: &str,
+    id_column: &str,
+    order: SortOrder,
+    cursor: Option<&Cursor>,
+) {
+    let order = match cursor {
+        Some(cursor) if cursor.direction == CursorDirection::Prev => order.reversed(),
+        _ => order,
+    };
+
+    builder
+        .push(" ORDER BY ")
+        .push(sort_expression)
+        .push(" ")
+        .push(order.as_sql())
+        .push(", ")
+        .push(id_column)
+        .push(" ")
+        .push(order.as_sql());
+}
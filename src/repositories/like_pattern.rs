@@ -0,0 +1,45 @@
+// Patterns built from user input. `%`, `_` and `\` in the input are escaped, so the
+// patterns must be used with `ESCAPE '\'`.
+
+// Wraps the input in `%` for a substring match
+pub fn contains(value: &str) -> String {
+    format!("%{}%", escape(value))
+}
+
+// Appends `%` to the input for a prefix match
+pub fn starts_with(value: &str) -> String {
+    format!("{}%", escape(value))
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contains_wraps_plain_input() {
+        assert_eq!(contains("portal"), "%portal%");
+    }
+
+    #[test]
+    fn contains_escapes_wildcards_and_backslashes() {
+        assert_eq!(contains("100%"), "%100\\%%");
+        assert_eq!(contains("a_b"), "%a\\_b%");
+        assert_eq!(contains("C:\\"), "%C:\\\\%");
+    }
+
+    #[test]
+    fn starts_with_escapes_the_prefix() {
+        assert_eq!(starts_with("50%_off"), "50\\%\\_off%");
+    }
+}
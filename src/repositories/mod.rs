@@ -2,3 +2,19 @@ pub mod game_repository;
 pub mod inventory_items;
 pub mod inventory_repository;
 pub mod user_repository;
+pub mod friend_repository;
+pub mod achievement_repository;
+pub mod ban_repository;
+pub mod progression_repository;
+pub mod group_repository;
+pub mod keyset;
+pub mod search_repository;
+pub mod profile_repository;
+pub mod privacy_repository;
+pub mod game_price_repository;
+pub mod watchlist_repository;
+pub mod steam_app_repository;
+pub mod news_repository;
+pub mod player_count_repository;
+pub mod game_review_repository;
+pub mod like_pattern;
@@ -0,0 +1,123 @@
+use sqlx::{Error as SqlxError, PgPool, Postgres, QueryBuilder};
+
+use crate::models::{
+    news::{
+        dto::{NewsItem, UpsertNewsItemSchema},
+        news_scope::NewsScope,
+    },
+    pagination::{Cursor, CursorRow, SortOrder},
+};
+use crate::repositories::keyset;
+
+pub struct NewsRepository;
+
+impl NewsRepository {
+    fn push_news_filters(
+        builder: &mut QueryBuilder<'_, Postgres>,
+        scope: NewsScope,
+        feed: Option<&str>,
+    ) {
+        match scope {
+            NewsScope::Game(game_id) => {
+                builder.push(" WHERE n.game_id = ").push_bind(game_id);
+            }
+            // Owned games and recently played ones both live in user_games
+            NewsScope::User(user_id) => {
+                builder
+                    .push(" WHERE n.game_id IN (SELECT ug.game_id FROM user_games ug WHERE ug.user_id = ")
+                    .push_bind(user_id)
+                    .push(")");
+            }
+        }
+        if let Some(feed) = feed {
+            builder
+                .push(" AND n.feed_name = ")
+                .push_bind(feed.to_string());
+        }
+    }
+
+    // Articles are deduplicated by gid, edited ones are updated in place.
+    // Returns true for new articles, false for edited ones and None when nothing changed.
+    pub async fn upsert_news_item(
+        pool: &PgPool,
+        body: UpsertNewsItemSchema,
+    ) -> Result<Option<bool>, SqlxError> {
+        sqlx::query_scalar!(
+            r#"INSERT INTO game_news
+            (game_id, gid, title, url, author, contents, feed_label, feed_name, is_external_url,
+                published_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (gid) DO UPDATE SET
+                title = EXCLUDED.title,
+                url = EXCLUDED.url,
+                author = EXCLUDED.author,
+                contents = EXCLUDED.contents,
+                feed_label = EXCLUDED.feed_label,
+                is_external_url = EXCLUDED.is_external_url,
+                updated_at = NOW()
+            WHERE (game_news.title, game_news.url, game_news.author, game_news.contents,
+                    game_news.feed_label, game_news.is_external_url)
+                IS DISTINCT FROM (EXCLUDED.title, EXCLUDED.url, EXCLUDED.author,
+                    EXCLUDED.contents, EXCLUDED.feed_label, EXCLUDED.is_external_url)
+            RETURNING (xmax = 0) AS "inserted!""#,
+            body.game_id,
+            body.gid,
+            body.title,
+            body.url,
+            body.author,
+            body.contents,
+            body.feed_label,
+            body.feed_name,
+            body.is_external_url,
+            body.published_at
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn get_news_paginated(
+        pool: &PgPool,
+        scope: NewsScope,
+        feed: Option<&str>,
+        cursor: Option<&Cursor>,
+        limit: i64,
+    ) -> Result<Vec<CursorRow<NewsItem>>, SqlxError> {
+        let mut builder = QueryBuilder::<Postgres>::new(
+            "SELECT n.gid, g.appid, g.name AS game_name, n.title, n.url, n.author, n.contents,
+                n.feed_label, n.feed_name, n.is_external_url, n.published_at,
+                n.published_at::TEXT AS cursor_value, n.id AS cursor_id
+            FROM game_news n
+            INNER JOIN games g ON g.id = n.game_id",
+        );
+        Self::push_news_filters(&mut builder, scope, feed);
+        keyset::push_keyset_filter(
+            &mut builder,
+            "n.published_at",
+            "TIMESTAMPTZ",
+            "n.id",
+            SortOrder::Desc,
+            cursor,
+        );
+        keyset::push_keyset_order(
+            &mut builder,
+            "n.published_at",
+            "n.id",
+            SortOrder::Desc,
+            cursor,
+        );
+        builder.push(" LIMIT ").push_bind(limit + 1);
+
+        builder.build_query_as().fetch_all(pool).await
+    }
+
+    pub async fn count_news(
+        pool: &PgPool,
+        scope: NewsScope,
+        feed: Option<&str>,
+    ) -> Result<i64, SqlxError> {
+        let mut builder = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM game_news n");
+        Self::push_news_filters(&mut builder, scope, feed);
+
+        builder.build_query_scalar::<i64>().fetch_one(pool).await
+    }
+}
@@ -0,0 +1,103 @@
+use sqlx::{Error as SqlxError, PgPool};
+
+use crate::models::game::dto::{PlayerCountBucket, PlayerCountPoint, TrendingGame};
+
+pub struct PlayerCountRepository;
+
+impl PlayerCountRepository {
+    // DLC, demos and apps the store no longer knows have no player counts. Pending games are
+    // skipped until a sync has set their type.
+    pub async fn get_tracked_appids(pool: &PgPool) -> Result<Vec<String>, SqlxError> {
+        sqlx::query_scalar!(
+            "SELECT appid FROM games
+            WHERE app_type = 'game' AND sync_status NOT IN ('pending', 'not_found')
+            ORDER BY appid"
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn insert_player_count(
+        pool: &PgPool,
+        game_id: uuid::Uuid,
+        player_count: i32,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            "INSERT INTO game_player_counts (game_id, player_count) VALUES ($1, $2)
+            ON CONFLICT DO NOTHING",
+            game_id,
+            player_count
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // Date bounds are inclusive on both ends, buckets without samples are left out
+    pub async fn get_player_counts(
+        pool: &PgPool,
+        game_id: uuid::Uuid,
+        bucket: PlayerCountBucket,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<PlayerCountPoint>, SqlxError> {
+        sqlx::query_as!(
+            PlayerCountPoint,
+            r#"SELECT date_trunc($2, recorded_at) AS "bucket_start!",
+                MIN(player_count) AS "min!", MAX(player_count) AS "max!",
+                AVG(player_count)::FLOAT8 AS "avg!", COUNT(*) AS "samples!"
+            FROM game_player_counts
+            WHERE game_id = $1 AND recorded_at >= $3::DATE AND recorded_at < $4::DATE + 1
+            GROUP BY 1
+            ORDER BY 1"#,
+            game_id,
+            bucket.as_str(),
+            from,
+            to
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    // Growth of the average players over the last `hours` against the `hours` before,
+    // games without samples in both windows are left out
+    pub async fn get_trending_games(
+        pool: &PgPool,
+        hours: i32,
+        min_players: i32,
+        limit: i64,
+    ) -> Result<Vec<TrendingGame>, SqlxError> {
+        sqlx::query_as!(
+            TrendingGame,
+            r#"WITH windows AS (
+                SELECT game_id,
+                    AVG(player_count) FILTER (
+                        WHERE recorded_at >= NOW() - make_interval(hours => $1)) AS current_avg,
+                    AVG(player_count) FILTER (
+                        WHERE recorded_at < NOW() - make_interval(hours => $1)) AS previous_avg,
+                    MAX(player_count) FILTER (
+                        WHERE recorded_at >= NOW() - make_interval(hours => $1)) AS current_peak
+                FROM game_player_counts
+                WHERE recorded_at >= NOW() - make_interval(hours => $1 * 2)
+                GROUP BY game_id
+            )
+            SELECT g.appid, g.name,
+                w.current_avg::FLOAT8 AS "current_avg!",
+                w.previous_avg::FLOAT8 AS "previous_avg!",
+                w.current_peak AS "current_peak!",
+                ((w.current_avg - w.previous_avg) / w.previous_avg * 100)::FLOAT8
+                    AS "growth_percent!"
+            FROM windows w
+            INNER JOIN games g ON g.id = w.game_id
+            WHERE w.current_avg IS NOT NULL AND w.previous_avg > 0 AND w.current_avg >= $2::INTEGER
+            ORDER BY 6 DESC, w.current_avg DESC, g.appid
+            LIMIT $3"#,
+            hours,
+            min_players,
+            limit
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
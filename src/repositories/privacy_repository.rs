@@ -0,0 +1,189 @@
+use sqlx::{Error as SqlxError, PgPool};
+
+use crate::models::privacy::{dto::ErasedRows, privacy_action::PrivacyAction};
+
+pub struct PrivacyRepository;
+
+impl PrivacyRepository {
+    // Soft deleted users are included on purpose, their data is still stored until purged
+    pub async fn export_user_data(pool: &PgPool, steam_id: &str) -> Result<String, SqlxError> {
+        sqlx::query_scalar!(
+            r#"SELECT json_build_object(
+                'exported_at', NOW(),
+                'user', to_jsonb(u),
+                'games', COALESCE((
+                    SELECT jsonb_agg(
+                        (to_jsonb(ug) - 'user_id' - 'game_id')
+                            || jsonb_build_object('appid', g.appid, 'name', g.name)
+                        ORDER BY g.name)
+                    FROM user_games ug
+                    INNER JOIN games g ON g.id = ug.game_id
+                    WHERE ug.user_id = u.id), '[]'::jsonb),
+                'playtime_history', COALESCE((
+                    SELECT jsonb_agg(
+                        (to_jsonb(s) - 'user_id' - 'game_id')
+                            || jsonb_build_object('appid', g.appid, 'name', g.name)
+                        ORDER BY s.snapshot_date, g.name)
+                    FROM user_game_playtime_snapshots s
+                    INNER JOIN games g ON g.id = s.game_id
+                    WHERE s.user_id = u.id), '[]'::jsonb),
+                'inventory_items', COALESCE((
+                    SELECT jsonb_agg(to_jsonb(ii) - 'inventory_id' ORDER BY ii.app_id, ii.name)
+                    FROM inventory_items ii
+                    INNER JOIN inventories i ON i.id = ii.inventory_id
+                    WHERE i.user_id = u.id), '[]'::jsonb),
+                'friends', COALESCE((
+                    SELECT jsonb_agg(to_jsonb(f) - 'user_id' ORDER BY f.friend_since, f.friend_steam_id)
+                    FROM user_friends f
+                    WHERE f.user_id = u.id), '[]'::jsonb),
+                'achievements', COALESCE((
+                    SELECT jsonb_agg(
+                        (to_jsonb(ua) - 'user_id' - 'achievement_id')
+                            || jsonb_build_object(
+                                'appid', g.appid,
+                                'api_name', a.api_name,
+                                'display_name', a.display_name)
+                        ORDER BY ua.unlocked_at)
+                    FROM user_achievements ua
+                    INNER JOIN achievements a ON a.id = ua.achievement_id
+                    INNER JOIN games g ON g.id = a.game_id
+                    WHERE ua.user_id = u.id), '[]'::jsonb),
+                'bans', (SELECT to_jsonb(b) - 'user_id' FROM user_bans b WHERE b.user_id = u.id),
+                'ban_history', COALESCE((
+                    SELECT jsonb_agg(to_jsonb(h) - 'user_id' ORDER BY h.recorded_at)
+                    FROM user_ban_history h
+                    WHERE h.user_id = u.id), '[]'::jsonb),
+                'progression', (
+                    SELECT to_jsonb(p) - 'user_id' FROM user_progression p WHERE p.user_id = u.id),
+                'badges', COALESCE((
+                    SELECT jsonb_agg(to_jsonb(bd) - 'user_id' ORDER BY bd.badge_id, bd.appid)
+                    FROM user_badges bd
+                    WHERE bd.user_id = u.id), '[]'::jsonb),
+                'groups', COALESCE((
+                    SELECT jsonb_agg(jsonb_build_object('gid', gr.gid, 'joined_at', ug.created_at)
+                        ORDER BY gr.gid)
+                    FROM user_groups ug
+                    INNER JOIN groups gr ON gr.id = ug.group_id
+                    WHERE ug.user_id = u.id), '[]'::jsonb),
+                'watchlist', COALESCE((
+                    SELECT jsonb_agg(
+                        (to_jsonb(w) - 'user_id' - 'admin_id' - 'game_id')
+                            || jsonb_build_object('appid', g.appid, 'name', g.name)
+                        ORDER BY g.name, w.country_code)
+                    FROM watchlists w
+                    INNER JOIN games g ON g.id = w.game_id
+                    WHERE w.user_id = u.id), '[]'::jsonb),
+                'price_alerts', COALESCE((
+                    SELECT jsonb_agg(
+                        (to_jsonb(pa) - 'watchlist_id')
+                            || jsonb_build_object('appid', g.appid, 'country_code', w.country_code)
+                        ORDER BY pa.created_at)
+                    FROM price_alerts pa
+                    INNER JOIN watchlists w ON w.id = pa.watchlist_id
+                    INNER JOIN games g ON g.id = w.game_id
+                    WHERE w.user_id = u.id), '[]'::jsonb),
+                'privacy_requests', COALESCE((
+                    SELECT jsonb_agg(to_jsonb(pr) - 'steam_id' ORDER BY pr.created_at)
+                    FROM privacy_requests pr
+                    WHERE pr.steam_id = u.steam_id), '[]'::jsonb)
+            )::TEXT AS "export!"
+            FROM users u
+            WHERE u.steam_id = $1"#,
+            steam_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    // Everything hanging off the user row goes through ON DELETE CASCADE, only the
+    // friend lists of other users reference the account by Steam id
+    pub async fn erase_user(
+        pool: &PgPool,
+        steam_id: &str,
+        performed_by: &str,
+    ) -> Result<ErasedRows, SqlxError> {
+        let mut tx = pool.begin().await?;
+
+        let user_id = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE steam_id = $1 FOR UPDATE",
+            steam_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let erased = sqlx::query_as!(
+            ErasedRows,
+            r#"SELECT
+                (SELECT COUNT(*) FROM user_games WHERE user_id = $1) AS "games!",
+                (SELECT COUNT(*) FROM user_game_playtime_snapshots WHERE user_id = $1) AS "playtime_snapshots!",
+                (SELECT COUNT(*) FROM inventory_items ii
+                    INNER JOIN inventories i ON i.id = ii.inventory_id
+                    WHERE i.user_id = $1) AS "inventory_items!",
+                (SELECT COUNT(*) FROM user_friends WHERE user_id = $1) AS "friends!",
+                (SELECT COUNT(*) FROM user_friends
+                    WHERE friend_steam_id = $2 AND user_id <> $1) AS "friend_references!",
+                (SELECT COUNT(*) FROM user_achievements WHERE user_id = $1) AS "achievements!",
+                (SELECT COUNT(*) FROM user_bans WHERE user_id = $1) AS "bans!",
+                (SELECT COUNT(*) FROM user_ban_history WHERE user_id = $1) AS "ban_history!",
+                (SELECT COUNT(*) FROM user_progression WHERE user_id = $1) AS "progression!",
+                (SELECT COUNT(*) FROM user_badges WHERE user_id = $1) AS "badges!",
+                (SELECT COUNT(*) FROM user_groups WHERE user_id = $1) AS "groups!",
+                (SELECT COUNT(*) FROM watchlists WHERE user_id = $1) AS "watchlist!",
+                (SELECT COUNT(*) FROM price_alerts pa
+                    INNER JOIN watchlists w ON w.id = pa.watchlist_id
+                    WHERE w.user_id = $1) AS "price_alerts!""#,
+            user_id,
+            steam_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "DELETE FROM user_friends WHERE friend_steam_id = $1 AND user_id <> $2",
+            steam_id,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!("DELETE FROM users WHERE id = $1", user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query!(
+            "INSERT INTO privacy_requests (steam_id, action, performed_by, rows_affected)
+            VALUES ($1, $2, $3, $4)",
+            steam_id,
+            PrivacyAction::Erase.as_str(),
+            performed_by,
+            erased.total() as i32
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(erased)
+    }
+
+    pub async fn record_request(
+        pool: &PgPool,
+        steam_id: &str,
+        action: PrivacyAction,
+        performed_by: &str,
+        rows_affected: i32,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            "INSERT INTO privacy_requests (steam_id, action, performed_by, rows_affected)
+            VALUES ($1, $2, $3, $4)",
+            steam_id,
+            action.as_str(),
+            performed_by,
+            rows_affected
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use sqlx::{Error as SqlxError, PgPool};
+use uuid::Uuid;
+
+use crate::models::profile::dto::{InventoryTypeCount, SyncTimes};
+
+pub struct ProfileRepository;
+
+impl ProfileRepository {
+    pub async fn get_inventory_type_counts(
+        pool: &PgPool,
+        user_id: Uuid,
+    ) -> Result<Vec<InventoryTypeCount>, SqlxError> {
+        sqlx::query_as!(
+            InventoryTypeCount,
+            r#"SELECT ii.app_id, ii.item_type, COUNT(*) AS "item_count!"
+            FROM inventory_items ii
+            INNER JOIN inventories i ON i.id = ii.inventory_id
+            WHERE i.user_id = $1
+            GROUP BY ii.app_id, ii.item_type
+            ORDER BY ii.app_id, COUNT(*) DESC, ii.item_type"#,
+            user_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_sync_times(pool: &PgPool, user_id: Uuid) -> Result<SyncTimes, SqlxError> {
+        sqlx::query_as!(
+            SyncTimes,
+            "SELECT u.updated_at AS profile_synced_at,
+                (SELECT MAX(ug.updated_at) FROM user_games ug WHERE ug.user_id = u.id) AS games_synced_at,
+                (SELECT MAX(ii.updated_at) FROM inventory_items ii
+                    INNER JOIN inventories i ON i.id = ii.inventory_id
+                    WHERE i.user_id = u.id) AS inventory_synced_at,
+                (SELECT MAX(f.updated_at) FROM user_friends f WHERE f.user_id = u.id) AS friends_synced_at,
+                (SELECT b.updated_at FROM user_bans b WHERE b.user_id = u.id) AS bans_synced_at,
+                (SELECT p.updated_at FROM user_progression p WHERE p.user_id = u.id) AS progression_synced_at
+            FROM users u
+            WHERE u.id = $1",
+            user_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+}
@@ -0,0 +1,129 @@
+use sqlx::{Error as SqlxError, PgPool};
+use uuid::Uuid;
+
+use crate::models::{
+    progression::{
+        badge::UserBadgeModel,
+        dto::{
+            Badge, LeaderboardEntry, LeaderboardSort, ProgressionSummary, UpsertUserBadgeSchema,
+            UpsertUserProgressionSchema,
+        },
+        progression::UserProgressionModel,
+    },
+    user::constants::PUBLIC_VISIBILITY,
+};
+
+pub struct ProgressionRepository;
+
+impl ProgressionRepository {
+    pub async fn upsert_progression(
+        pool: &PgPool,
+        body: UpsertUserProgressionSchema,
+    ) -> Result<UserProgressionModel, SqlxError> {
+        sqlx::query_as!(
+            UserProgressionModel,
+            "INSERT into user_progression
+            (user_id, steam_level, player_xp, xp_needed_to_level_up, xp_needed_current_level)
+            values ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id) DO UPDATE SET
+                steam_level = EXCLUDED.steam_level,
+                player_xp = EXCLUDED.player_xp,
+                xp_needed_to_level_up = EXCLUDED.xp_needed_to_level_up,
+                xp_needed_current_level = EXCLUDED.xp_needed_current_level,
+                updated_at = NOW()
+            RETURNING *",
+            body.user_id,
+            body.steam_level,
+            body.player_xp,
+            body.xp_needed_to_level_up,
+            body.xp_needed_current_level
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn upsert_badge(
+        pool: &PgPool,
+        body: UpsertUserBadgeSchema,
+    ) -> Result<UserBadgeModel, SqlxError> {
+        sqlx::query_as!(
+            UserBadgeModel,
+            "INSERT into user_badges
+            (user_id, badge_id, appid, level, xp, scarcity, completed_at)
+            values ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (user_id, badge_id, appid) DO UPDATE SET
+                level = EXCLUDED.level,
+                xp = EXCLUDED.xp,
+                scarcity = EXCLUDED.scarcity,
+                completed_at = EXCLUDED.completed_at,
+                updated_at = NOW()
+            RETURNING *",
+            body.user_id,
+            body.badge_id,
+            body.appid,
+            body.level,
+            body.xp,
+            body.scarcity,
+            body.completed_at
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn get_progression_summary_by_steam_id(
+        pool: &PgPool,
+        steam_id: &str,
+    ) -> Result<Option<ProgressionSummary>, SqlxError> {
+        sqlx::query_as!(
+            ProgressionSummary,
+            r#"SELECT p.steam_level, p.player_xp, p.xp_needed_to_level_up, p.xp_needed_current_level,
+                (SELECT COUNT(*) FROM user_badges b WHERE b.user_id = p.user_id) AS "badge_count!",
+                p.updated_at
+            FROM user_progression p
+            INNER JOIN users u ON u.id = p.user_id
+            WHERE u.steam_id = $1 AND u.deleted_at IS NULL"#,
+            steam_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn get_user_badges(pool: &PgPool, user_id: Uuid) -> Result<Vec<Badge>, SqlxError> {
+        sqlx::query_as!(
+            Badge,
+            "SELECT badge_id, NULLIF(appid, 0) AS appid, level, xp, scarcity, completed_at
+            FROM user_badges
+            WHERE user_id = $1
+            ORDER BY completed_at DESC NULLS LAST, badge_id",
+            user_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_leaderboard(
+        pool: &PgPool,
+        sort: LeaderboardSort,
+        limit: i64,
+        public_profiles_only: bool,
+    ) -> Result<Vec<LeaderboardEntry>, SqlxError> {
+        sqlx::query_as!(
+            LeaderboardEntry,
+            r#"SELECT
+                RANK() OVER (ORDER BY CASE WHEN $1 = 'xp' THEN p.player_xp ELSE p.steam_level END DESC) AS "rank!",
+                u.steam_id, u.username, u.avatar, p.steam_level, p.player_xp,
+                (SELECT COUNT(*) FROM user_badges b WHERE b.user_id = p.user_id) AS "badge_count!"
+            FROM user_progression p
+            INNER JOIN users u ON u.id = p.user_id
+            WHERE u.deleted_at IS NULL AND (NOT $3 OR u.visibility = $4)
+            ORDER BY 1, p.player_xp DESC, u.username
+            LIMIT $2"#,
+            sort.as_str(),
+            limit,
+            public_profiles_only,
+            PUBLIC_VISIBILITY
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,78 @@
+use sqlx::{Error as SqlxError, PgPool};
+
+use crate::{
+    models::{search::dto::SearchResult, user::constants::PUBLIC_VISIBILITY},
+    repositories::like_pattern,
+};
+
+pub struct SearchRepository;
+
+impl SearchRepository {
+    // The tsvector expressions match the GIN expression indexes on games and inventory_items.
+    // Highlights are HTML with only the <mark> tags left unescaped. Items of deleted users are
+    // hidden, and those of private profiles unless `public_profiles_only` is false.
+    pub async fn search(
+        pool: &PgPool,
+        query: &str,
+        result_type: Option<&str>,
+        limit: i64,
+        public_profiles_only: bool,
+    ) -> Result<Vec<SearchResult>, SqlxError> {
+        sqlx::query_as!(
+            SearchResult,
+            r#"SELECT result_type AS "result_type!", id AS "id!", title AS "title!", subtitle,
+                rank AS "rank!", highlight AS "highlight!"
+            FROM (
+                SELECT 'user' AS result_type, u.steam_id AS id, u.username AS title, NULL::TEXT AS subtitle,
+                    similarity(u.username, $1) AS rank,
+                    ts_headline('simple', html_escape(u.username), plainto_tsquery('simple', $1),
+                        'StartSel=<mark>, StopSel=</mark>, HighlightAll=true') AS highlight
+                FROM users u
+                WHERE (u.username % $1 OR u.username ILIKE $4 ESCAPE '\') AND u.deleted_at IS NULL
+
+                UNION ALL
+
+                SELECT 'game', g.appid, g.name, g.short_description,
+                    ts_rank(
+                        setweight(to_tsvector('english', g.name), 'A') || setweight(to_tsvector('english', COALESCE(g.short_description, '')), 'B'),
+                        websearch_to_tsquery('english', $1)
+                    ) + similarity(g.name, $1),
+                    ts_headline('english', html_escape(CONCAT_WS(' ', g.name, g.short_description)), websearch_to_tsquery('english', $1),
+                        'StartSel=<mark>, StopSel=</mark>, MaxFragments=1, MaxWords=20, MinWords=5')
+                FROM games g
+                WHERE (setweight(to_tsvector('english', g.name), 'A') || setweight(to_tsvector('english', COALESCE(g.short_description, '')), 'B'))
+                        @@ websearch_to_tsquery('english', $1)
+                    OR g.name % $1
+
+                UNION ALL
+
+                SELECT 'item', ii.id::TEXT, ii.name, ii.item_type,
+                    ts_rank(
+                        setweight(to_tsvector('english', ii.name), 'A') || setweight(to_tsvector('english', ii.item_type), 'B'),
+                        websearch_to_tsquery('english', $1)
+                    ) + similarity(ii.name, $1),
+                    ts_headline('english', html_escape(ii.name || ' ' || ii.item_type), websearch_to_tsquery('english', $1),
+                        'StartSel=<mark>, StopSel=</mark>, HighlightAll=true')
+                FROM inventory_items ii
+                INNER JOIN inventories i ON i.id = ii.inventory_id
+                INNER JOIN users u ON u.id = i.user_id
+                WHERE ((setweight(to_tsvector('english', ii.name), 'A') || setweight(to_tsvector('english', ii.item_type), 'B'))
+                        @@ websearch_to_tsquery('english', $1)
+                    OR ii.name % $1)
+                    AND u.deleted_at IS NULL
+                    AND (NOT $5 OR u.visibility = $6)
+            ) results
+            WHERE $2::TEXT IS NULL OR result_type = $2
+            ORDER BY rank DESC, title
+            LIMIT $3"#,
+            query,
+            result_type,
+            limit,
+            like_pattern::contains(query),
+            public_profiles_only,
+            PUBLIC_VISIBILITY
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,77 @@
+use sqlx::{Error as SqlxError, PgPool};
+
+use crate::models::steam_app::steam_app::SteamApp;
+use crate::repositories::like_pattern;
+
+pub struct SteamAppRepository;
+
+impl SteamAppRepository {
+    // Only new and renamed apps are written, returns how many
+    pub async fn upsert_steam_apps(
+        pool: &PgPool,
+        appids: &[String],
+        names: &[String],
+    ) -> Result<u64, SqlxError> {
+        let rows = sqlx::query!(
+            "INSERT INTO steam_apps (appid, name)
+            SELECT * FROM UNNEST($1::VARCHAR[], $2::TEXT[])
+            ON CONFLICT (appid) DO UPDATE SET name = EXCLUDED.name, updated_at = NOW()
+            WHERE steam_apps.name IS DISTINCT FROM EXCLUDED.name",
+            appids,
+            names
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        Ok(rows)
+    }
+
+    // Exact matches first, then prefix matches, then by similarity
+    pub async fn search_steam_apps(
+        pool: &PgPool,
+        query: &str,
+        limit: i64,
+    ) -> Result<Vec<SteamApp>, SqlxError> {
+        sqlx::query_as!(
+            SteamApp,
+            r#"SELECT s.appid, s.name,
+                EXISTS(SELECT 1 FROM games g WHERE g.appid = s.appid) AS "stored!"
+            FROM steam_apps s
+            WHERE s.name ILIKE $3 ESCAPE '\' OR s.name % $1
+            ORDER BY LOWER(s.name) = LOWER($1) DESC,
+                s.name ILIKE $4 ESCAPE '\' DESC,
+                similarity(s.name, $1) DESC,
+                LENGTH(s.name),
+                s.appid
+            LIMIT $2"#,
+            query,
+            limit,
+            like_pattern::contains(query),
+            like_pattern::starts_with(query)
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    // Case insensitive, several apps often share a name (e.g. re-releases)
+    pub async fn get_steam_apps_by_name(
+        pool: &PgPool,
+        name: &str,
+        limit: i64,
+    ) -> Result<Vec<SteamApp>, SqlxError> {
+        sqlx::query_as!(
+            SteamApp,
+            r#"SELECT s.appid, s.name,
+                EXISTS(SELECT 1 FROM games g WHERE g.appid = s.appid) AS "stored!"
+            FROM steam_apps s
+            WHERE LOWER(s.name) = LOWER($1)
+            ORDER BY s.appid::BIGINT
+            LIMIT $2"#,
+            name,
+            limit
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -1,90 +1,145 @@
+use crate::models::pagination::{Cursor, CursorRow, SortOrder};
 use crate::models::{
     dto::{CreateUserSchema, GetUser, update_user::UpdateUser},
-    user::{dto::get_users::GetUsers, user::UserModel},
+    user::{
+        constants::PUBLIC_VISIBILITY,
+        dto::get_users::GetUsers,
+        pagination::user_filters::{UserFilters, UserSortField},
+        user::UserModel,
+    },
 };
-use sqlx::{Error as SqlxError, PgPool};
+use crate::repositories::{keyset, like_pattern};
+use sqlx::{Error as SqlxError, PgPool, Postgres, QueryBuilder};
 use uuid::Uuid;
 
 pub struct UserRepository;
 
 impl UserRepository {
+    fn push_user_filters(builder: &mut QueryBuilder<'_, Postgres>, filters: &UserFilters) {
+        builder.push(" WHERE deleted_at IS NULL");
+
+        if let Some(username) = &filters.username {
+            builder
+                .push(" AND username ILIKE ")
+                .push_bind(like_pattern::contains(username))
+                .push(" ESCAPE '\\'");
+        }
+        if let Some(banned) = filters.banned {
+            builder
+                .push(" AND COALESCE((SELECT b.is_banned FROM user_bans b WHERE b.user_id = users.id), false) = ")
+                .push_bind(banned);
+        }
+        if let Some(group) = &filters.group {
+            builder
+                .push(" AND EXISTS(SELECT 1 FROM user_groups ug INNER JOIN groups g ON g.id = ug.group_id WHERE ug.user_id = users.id AND g.gid = ")
+                .push_bind(group.clone())
+                .push(")");
+        }
+        if let Some(country) = &filters.country {
+            builder
+                .push(" AND UPPER(country) = UPPER(")
+                .push_bind(country.clone())
+                .push(")");
+        }
+        if let Some(persona_state) = filters.persona_state {
+            builder
+                .push(" AND persona_state = ")
+                .push_bind(persona_state);
+        }
+        if let Some(visibility) = filters.visibility {
+            builder.push(" AND visibility = ").push_bind(visibility);
+        }
+        if let Some(current_game) = &filters.current_game {
+            builder
+                .push(" AND current_game ILIKE ")
+                .push_bind(like_pattern::contains(current_game))
+                .push(" ESCAPE '\\'");
+        }
+        if let Some(gameid) = &filters.gameid {
+            builder.push(" AND gameid = ").push_bind(gameid.clone());
+        }
+        // Date ranges are inclusive on both ends
+        if let Some(from) = filters.steam_created_from {
+            builder
+                .push(" AND steam_created_at >= ")
+                .push_bind(from)
+                .push("::DATE");
+        }
+        if let Some(to) = filters.steam_created_to {
+            builder
+                .push(" AND steam_created_at < ")
+                .push_bind(to)
+                .push("::DATE + 1");
+        }
+        if let Some(from) = filters.created_from {
+            builder
+                .push(" AND created_at >= ")
+                .push_bind(from)
+                .push("::DATE");
+        }
+        if let Some(to) = filters.created_to {
+            builder
+                .push(" AND created_at < ")
+                .push_bind(to)
+                .push("::DATE + 1");
+        }
+        if filters.public_profiles_only {
+            builder
+                .push(" AND visibility = ")
+                .push_bind(PUBLIC_VISIBILITY);
+        }
+    }
+
     pub async fn get_users_paginated(
         pool: &PgPool,
-        username_filter: Option<&str>,
+        filters: &UserFilters,
+        sort: UserSortField,
+        order: SortOrder,
+        cursor: Option<&Cursor>,
         limit: i64,
         offset: i64,
-    ) -> Result<Vec<GetUsers>, SqlxError> {
-        match username_filter {
-            Some(username) => {
-                let search_pattern = format!("%{}%", username);
-                sqlx::query_as!(
-                    GetUsers,
-                    "SELECT steam_id, username, avatar, pf_url, current_game 
-                     FROM users 
-                     WHERE username ILIKE $1
-                     ORDER BY username
-                     LIMIT $2 OFFSET $3",
-                    search_pattern,
-                    limit,
-                    offset
-                )
-                .fetch_all(pool)
-                .await
-            }
-            None => {
-                sqlx::query_as!(
-                    GetUsers,
-                    "SELECT steam_id, username, avatar, pf_url, current_game 
-                     FROM users 
-                     ORDER BY username
-                     LIMIT $1 OFFSET $2",
-                    limit,
-                    offset
-                )
-                .fetch_all(pool)
-                .await
-            }
-        }
-    }
+    ) -> Result<Vec<CursorRow<GetUsers>>, SqlxError> {
+        let sort_expression = sort.as_sort_expression();
 
-    pub async fn count_users(
-        pool: &PgPool,
-        username_filter: Option<&str>,
-    ) -> Result<i64, SqlxError> {
-        let count = match username_filter {
-            Some(username) => {
-                let search_pattern = format!("%{}%", username);
-                sqlx::query_scalar!(
-                    "SELECT COUNT(*) FROM users WHERE username ILIKE $1",
-                    search_pattern
-                )
-                .fetch_one(pool)
-                .await?
-            }
-            None => {
-                sqlx::query_scalar!("SELECT COUNT(*) FROM users")
-                    .fetch_one(pool)
-                    .await?
-            }
-        };
-
-        Ok(count.unwrap_or(0))
+        let mut builder = QueryBuilder::<Postgres>::new(
+            "SELECT steam_id, username, avatar, pf_url, current_game, visibility, ",
+        );
+        builder
+            .push(sort_expression)
+            .push("::TEXT AS cursor_value, id AS cursor_id FROM users");
+        Self::push_user_filters(&mut builder, filters);
+
+        // Sort expression and direction come from whitelisted enums, never from raw input
+        keyset::push_keyset_filter(
+            &mut builder,
+            sort_expression,
+            sort.as_sql_type(),
+            "id",
+            order,
+            cursor,
+        );
+        keyset::push_keyset_order(&mut builder, sort_expression, "id", order, cursor);
+
+        // One extra row tells whether there is another page
+        builder
+            .push(" LIMIT ")
+            .push_bind(limit + 1)
+            .push(" OFFSET ")
+            .push_bind(offset);
+
+        builder.build_query_as().fetch_all(pool).await
     }
 
-    pub async fn get_users_with_count(
-        pool: &PgPool,
-        username_filter: Option<&str>,
-        limit: i64,
-        offset: i64,
-    ) -> Result<(Vec<GetUsers>, i64), SqlxError> {
-        let users = Self::get_users_paginated(pool, username_filter, limit, offset).await?;
-        let total = Self::count_users(pool, username_filter).await?;
-        Ok((users, total))
+    pub async fn count_users(pool: &PgPool, filters: &UserFilters) -> Result<i64, SqlxError> {
+        let mut builder = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM users");
+        Self::push_user_filters(&mut builder, filters);
+
+        builder.build_query_scalar::<i64>().fetch_one(pool).await
     }
 
     pub async fn check_if_user_exits(pool: &PgPool, steam_id: &str) -> Result<bool, SqlxError> {
         let existing_query = sqlx::query_scalar!(
-            "SELECT EXISTS(SELECT 1 FROM users WHERE steam_id = $1)",
+            "SELECT EXISTS(SELECT 1 FROM users WHERE steam_id = $1 AND deleted_at IS NULL)",
             steam_id
         )
         .fetch_one(pool)
@@ -116,11 +171,66 @@ impl UserRepository {
         ).fetch_one(pool).await
     }
 
-    pub async fn delete_user(pool: &PgPool, user_id: Uuid) -> Result<u64, SqlxError> {
-        let rows = sqlx::query!("DELETE FROM users WHERE id = $1", user_id)
-            .execute(pool)
-            .await?
-            .rows_affected();
+    pub async fn check_if_user_is_deleted(
+        pool: &PgPool,
+        steam_id: &str,
+    ) -> Result<bool, SqlxError> {
+        let deleted_query = sqlx::query_scalar!(
+            "SELECT EXISTS(SELECT 1 FROM users WHERE steam_id = $1 AND deleted_at IS NOT NULL)",
+            steam_id
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(deleted_query.unwrap_or(false))
+    }
+
+    // `user_id` matches the users.id uuid, `steam_id` the Steam id, either may be set
+    pub async fn soft_delete_user(
+        pool: &PgPool,
+        user_id: Option<Uuid>,
+        steam_id: &str,
+    ) -> Result<u64, SqlxError> {
+        let rows = sqlx::query!(
+            "UPDATE users SET deleted_at = NOW()
+            WHERE (id = $1 OR steam_id = $2) AND deleted_at IS NULL",
+            user_id,
+            steam_id
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        Ok(rows)
+    }
+
+    pub async fn restore_user(
+        pool: &PgPool,
+        user_id: Option<Uuid>,
+        steam_id: &str,
+    ) -> Result<u64, SqlxError> {
+        let rows = sqlx::query!(
+            "UPDATE users SET deleted_at = NULL, updated_at = NOW()
+            WHERE (id = $1 OR steam_id = $2) AND deleted_at IS NOT NULL",
+            user_id,
+            steam_id
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        Ok(rows)
+    }
+
+    // Inventories and their items go with the user through ON DELETE CASCADE
+    pub async fn purge_deleted_users(pool: &PgPool, retention_days: i32) -> Result<u64, SqlxError> {
+        let rows = sqlx::query!(
+            "DELETE FROM users WHERE deleted_at < NOW() - make_interval(days => $1)",
+            retention_days
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
 
         Ok(rows)
     }
@@ -128,21 +238,41 @@ impl UserRepository {
     pub async fn get_user(pool: &PgPool, steam_id: &str) -> Result<GetUser, SqlxError> {
         let fetched_user = sqlx::query_as!(
             GetUser,
-            "SELECT steam_id, username, avatar, pf_url, country, current_game, persona_state, visibility, steam_created_at, gameid FROM users WHERE steam_id = $1",
+            "SELECT steam_id, username, avatar, pf_url, country, current_game, persona_state, visibility, steam_created_at, gameid FROM users WHERE steam_id = $1 AND deleted_at IS NULL",
             steam_id
         ).fetch_one(pool).await?;
 
         Ok(fetched_user)
     }
 
+    pub async fn get_all_steam_ids(pool: &PgPool) -> Result<Vec<String>, SqlxError> {
+        sqlx::query_scalar!(
+            "SELECT steam_id FROM users WHERE deleted_at IS NULL ORDER BY created_at"
+        )
+        .fetch_all(pool)
+        .await
+    }
+
     pub async fn get_user_id_by_steam_id(pool: &PgPool, steam_id: &str) -> Result<Uuid, SqlxError> {
-        let user_id = sqlx::query_scalar!("SELECT id FROM users WHERE steam_id = $1", steam_id)
-            .fetch_one(pool)
-            .await?;
+        let user_id = sqlx::query_scalar!(
+            "SELECT id FROM users WHERE steam_id = $1 AND deleted_at IS NULL",
+            steam_id
+        )
+        .fetch_one(pool)
+        .await?;
 
         Ok(user_id)
     }
 
+    pub async fn get_visibility(pool: &PgPool, steam_id: &str) -> Result<i32, SqlxError> {
+        sqlx::query_scalar!(
+            "SELECT visibility FROM users WHERE steam_id = $1 AND deleted_at IS NULL",
+            steam_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+
     pub async fn update_user(
         pool: &PgPool,
         body: UpdateUser,
@@ -160,7 +290,7 @@ impl UserRepository {
                 country = COALESCE($7, country), 
                 gameid = $8, 
                 updated_at = NOW() 
-            WHERE steam_id = $9
+            WHERE steam_id = $9 AND deleted_at IS NULL
             RETURNING username, pf_url, avatar, persona_state, visibility, current_game, country, gameid",
             body.username,
             body.pf_url,
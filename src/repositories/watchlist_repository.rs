@@ -0,0 +1,279 @@
+use sqlx::{Error as SqlxError, PgPool};
+
+use crate::models::watchlist::{
+    dto::{PriceAlert, WatchlistItem},
+    watchlist_owner::{WatchlistOwner, WatchlistOwnerIds},
+    webhook_status::WebhookStatus,
+};
+
+pub struct WatchlistRepository;
+
+impl WatchlistRepository {
+    // RowNotFound when neither the user nor the admin exists
+    pub async fn resolve_owner(
+        pool: &PgPool,
+        owner: &WatchlistOwner,
+    ) -> Result<WatchlistOwnerIds, SqlxError> {
+        sqlx::query_as!(
+            WatchlistOwnerIds,
+            r#"SELECT u.id AS "user_id?", NULL::UUID AS "admin_id?"
+            FROM users u WHERE u.steam_id = $1 AND u.deleted_at IS NULL
+            UNION ALL
+            SELECT NULL::UUID, a.id FROM admins a WHERE a.username = $2"#,
+            owner.steam_id(),
+            owner.admin_username()
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    // Updating an entry clears its last alert, so the new thresholds can alert right away
+    pub async fn upsert_watchlist_item(
+        pool: &PgPool,
+        owner: &WatchlistOwnerIds,
+        game_id: uuid::Uuid,
+        country_code: &str,
+        target_price: Option<i32>,
+        discount_threshold: Option<i32>,
+    ) -> Result<uuid::Uuid, SqlxError> {
+        sqlx::query_scalar!(
+            r#"WITH updated AS (
+                UPDATE watchlists SET
+                    target_price = $5,
+                    discount_threshold = $6,
+                    last_alerted_price = NULL,
+                    updated_at = NOW()
+                WHERE user_id IS NOT DISTINCT FROM $1 AND admin_id IS NOT DISTINCT FROM $2
+                    AND game_id = $3 AND country_code = $4
+                RETURNING id
+            ),
+            inserted AS (
+                INSERT INTO watchlists
+                (user_id, admin_id, game_id, country_code, target_price, discount_threshold)
+                SELECT $1, $2, $3, $4, $5, $6
+                WHERE NOT EXISTS (SELECT 1 FROM updated)
+                RETURNING id
+            )
+            SELECT id AS "id!" FROM updated
+            UNION ALL
+            SELECT id FROM inserted"#,
+            owner.user_id,
+            owner.admin_id,
+            game_id,
+            country_code,
+            target_price,
+            discount_threshold
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn get_watchlist_item(
+        pool: &PgPool,
+        id: uuid::Uuid,
+    ) -> Result<WatchlistItem, SqlxError> {
+        sqlx::query_as!(
+            WatchlistItem,
+            r#"SELECT g.appid, g.name, w.country_code, w.target_price, w.discount_threshold,
+                p.currency AS "currency?", p.final_price AS "final_price?",
+                p.discount_percent AS "discount_percent?", w.created_at, w.updated_at
+            FROM watchlists w
+            INNER JOIN games g ON g.id = w.game_id
+            LEFT JOIN LATERAL (
+                SELECT gp.currency, gp.final_price, gp.discount_percent FROM game_prices gp
+                WHERE gp.game_id = w.game_id AND gp.country_code = w.country_code
+                ORDER BY gp.snapshot_date DESC
+                LIMIT 1
+            ) p ON true
+            WHERE w.id = $1"#,
+            id
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn get_watchlist(
+        pool: &PgPool,
+        owner: &WatchlistOwnerIds,
+    ) -> Result<Vec<WatchlistItem>, SqlxError> {
+        sqlx::query_as!(
+            WatchlistItem,
+            r#"SELECT g.appid, g.name, w.country_code, w.target_price, w.discount_threshold,
+                p.currency AS "currency?", p.final_price AS "final_price?",
+                p.discount_percent AS "discount_percent?", w.created_at, w.updated_at
+            FROM watchlists w
+            INNER JOIN games g ON g.id = w.game_id
+            LEFT JOIN LATERAL (
+                SELECT gp.currency, gp.final_price, gp.discount_percent FROM game_prices gp
+                WHERE gp.game_id = w.game_id AND gp.country_code = w.country_code
+                ORDER BY gp.snapshot_date DESC
+                LIMIT 1
+            ) p ON true
+            WHERE w.user_id IS NOT DISTINCT FROM $1 AND w.admin_id IS NOT DISTINCT FROM $2
+            ORDER BY g.name, w.country_code"#,
+            owner.user_id,
+            owner.admin_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    // Without a region every region of the game is removed
+    pub async fn remove_watchlist_item(
+        pool: &PgPool,
+        owner: &WatchlistOwnerIds,
+        appid: &str,
+        country_code: Option<&str>,
+    ) -> Result<u64, SqlxError> {
+        let rows = sqlx::query!(
+            "DELETE FROM watchlists w
+            USING games g
+            WHERE g.id = w.game_id
+                AND w.user_id IS NOT DISTINCT FROM $1 AND w.admin_id IS NOT DISTINCT FROM $2
+                AND g.appid = $3
+                AND ($4::TEXT IS NULL OR w.country_code = UPPER($4))",
+            owner.user_id,
+            owner.admin_id,
+            appid,
+            country_code
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        Ok(rows)
+    }
+
+    // Entries that no longer match their game's latest price get their last alert
+    // cleared, so the next sale alerts again
+    pub async fn clear_recovered_watchlists(
+        pool: &PgPool,
+        game_id: uuid::Uuid,
+    ) -> Result<u64, SqlxError> {
+        let rows = sqlx::query!(
+            "UPDATE watchlists w SET last_alerted_price = NULL
+            FROM (
+                SELECT DISTINCT ON (country_code) country_code, final_price, discount_percent
+                FROM game_prices
+                WHERE game_id = $1
+                ORDER BY country_code, snapshot_date DESC
+            ) l
+            WHERE w.game_id = $1
+                AND l.country_code = w.country_code
+                AND (w.user_id IS NULL
+                    OR EXISTS (SELECT 1 FROM users u WHERE u.id = w.user_id AND u.deleted_at IS NULL))
+                AND w.last_alerted_price IS NOT NULL
+                AND NOT COALESCE(
+                    l.final_price <= w.target_price OR l.discount_percent >= w.discount_threshold,
+                    false)",
+            game_id
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        Ok(rows)
+    }
+
+    // Alerts once per sale, and again whenever the price drops below the last alert
+    pub async fn create_price_alerts(
+        pool: &PgPool,
+        game_id: uuid::Uuid,
+    ) -> Result<Vec<uuid::Uuid>, SqlxError> {
+        sqlx::query_scalar!(
+            "WITH latest AS (
+                SELECT DISTINCT ON (country_code)
+                    country_code, currency, initial_price, final_price, discount_percent
+                FROM game_prices
+                WHERE game_id = $1
+                ORDER BY country_code, snapshot_date DESC
+            ),
+            triggered AS (
+                SELECT w.id, l.currency, l.initial_price, l.final_price, l.discount_percent
+                FROM watchlists w
+                INNER JOIN latest l ON l.country_code = w.country_code
+                LEFT JOIN users u ON u.id = w.user_id
+                WHERE w.game_id = $1
+                    AND (w.user_id IS NULL OR u.deleted_at IS NULL)
+                    AND (l.final_price <= w.target_price OR l.discount_percent >= w.discount_threshold)
+                    AND (w.last_alerted_price IS NULL OR l.final_price < w.last_alerted_price)
+            ),
+            alerted AS (
+                UPDATE watchlists w SET last_alerted_price = t.final_price
+                FROM triggered t
+                WHERE w.id = t.id
+            )
+            INSERT INTO price_alerts
+            (watchlist_id, currency, initial_price, final_price, discount_percent)
+            SELECT id, currency, initial_price, final_price, discount_percent FROM triggered
+            RETURNING id",
+            game_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_price_alerts(
+        pool: &PgPool,
+        owner: &WatchlistOwnerIds,
+        limit: i64,
+    ) -> Result<Vec<PriceAlert>, SqlxError> {
+        sqlx::query_as!(
+            PriceAlert,
+            r#"SELECT pa.id, g.appid, g.name, w.country_code, pa.currency, pa.initial_price,
+                pa.final_price, pa.discount_percent, w.target_price, w.discount_threshold,
+                u.steam_id AS "steam_id?", a.username AS "admin?", pa.webhook_status, pa.created_at
+            FROM price_alerts pa
+            INNER JOIN watchlists w ON w.id = pa.watchlist_id
+            INNER JOIN games g ON g.id = w.game_id
+            LEFT JOIN users u ON u.id = w.user_id
+            LEFT JOIN admins a ON a.id = w.admin_id
+            WHERE w.user_id IS NOT DISTINCT FROM $1 AND w.admin_id IS NOT DISTINCT FROM $2
+            ORDER BY pa.created_at DESC, pa.id
+            LIMIT $3"#,
+            owner.user_id,
+            owner.admin_id,
+            limit
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_price_alerts_by_ids(
+        pool: &PgPool,
+        ids: &[uuid::Uuid],
+    ) -> Result<Vec<PriceAlert>, SqlxError> {
+        sqlx::query_as!(
+            PriceAlert,
+            r#"SELECT pa.id, g.appid, g.name, w.country_code, pa.currency, pa.initial_price,
+                pa.final_price, pa.discount_percent, w.target_price, w.discount_threshold,
+                u.steam_id AS "steam_id?", a.username AS "admin?", pa.webhook_status, pa.created_at
+            FROM price_alerts pa
+            INNER JOIN watchlists w ON w.id = pa.watchlist_id
+            INNER JOIN games g ON g.id = w.game_id
+            LEFT JOIN users u ON u.id = w.user_id
+            LEFT JOIN admins a ON a.id = w.admin_id
+            WHERE pa.id = ANY($1)
+            ORDER BY pa.created_at, pa.id"#,
+            ids
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn set_webhook_status(
+        pool: &PgPool,
+        id: uuid::Uuid,
+        status: WebhookStatus,
+    ) -> Result<(), SqlxError> {
+        sqlx::query!(
+            "UPDATE price_alerts SET webhook_status = $1 WHERE id = $2",
+            status.as_str(),
+            id
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{
+    AppState,
+    services::{achievement_service::AchievementService, errors::games::get_errors::GetGameError},
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{appid}/achievements")]
+pub async fn get_game_achievements(
+    appid: web::Path<String>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match AchievementService::get_game_achievements(&data.db, &appid).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetGameError::GameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found"
+            })),
+            GetGameError::DatabaseError(err) => HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": format!("Database error: {:?}", err)
+            })),
+        },
+    }
+}
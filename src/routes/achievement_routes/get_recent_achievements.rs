@@ -0,0 +1,46 @@
+use crate::{
+    AppState,
+    models::achievement::{
+        constants::{MAX_RECENT_ACHIEVEMENTS_LIMIT, MIN_RECENT_ACHIEVEMENTS_LIMIT},
+        dto::RecentAchievementsQueryParams,
+    },
+    models::user::viewer::Viewer,
+    services::{
+        achievement_service::AchievementService,
+        errors::achievements::get_errors::GetAchievementsError,
+    },
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{steam_id}/achievements/recent")]
+pub async fn get_recent_achievements(
+    steam_id: web::Path<String>,
+    params: web::Query<RecentAchievementsQueryParams>,
+    data: web::Data<AppState>,
+    viewer: Viewer,
+) -> impl Responder {
+    let limit = params
+        .limit
+        .clamp(MIN_RECENT_ACHIEVEMENTS_LIMIT, MAX_RECENT_ACHIEVEMENTS_LIMIT);
+
+    match AchievementService::get_recent_achievements(&data.db, &steam_id, limit, viewer).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetAchievementsError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            GetAchievementsError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "Steam profile is private"
+            })),
+            GetAchievementsError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                }))
+            }
+        },
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{
+    AppState,
+    models::user::viewer::Viewer,
+    services::{
+        achievement_service::AchievementService,
+        errors::achievements::get_errors::GetAchievementsError,
+    },
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{steam_id}/achievements")]
+pub async fn get_user_achievements(
+    steam_id: web::Path<String>,
+    data: web::Data<AppState>,
+    viewer: Viewer,
+) -> impl Responder {
+    match AchievementService::get_user_completion(&data.db, &steam_id, viewer).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetAchievementsError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            GetAchievementsError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "Steam profile is private"
+            })),
+            GetAchievementsError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                }))
+            }
+        },
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{
+    AppState,
+    services::{
+        achievement_service::AchievementService,
+        errors::achievements::import_errors::ImportAchievementsError,
+    },
+};
+use actix_web::{HttpResponse, Responder, post, web};
+use serde_json::json;
+
+#[post("/{appid}/achievements/import")]
+pub async fn import_game_achievements(
+    appid: web::Path<String>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match AchievementService::import_game_schema(&data.db, &appid).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            ImportAchievementsError::GameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found"
+            })),
+            ImportAchievementsError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            ImportAchievementsError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "This user's game details are private"
+            })),
+            ImportAchievementsError::SteamApiError(msg) => HttpResponse::BadGateway().json(json!({
+                "status": "error",
+                "message": format!("Steam API error: {}", msg)
+            })),
+            ImportAchievementsError::DatabaseError(err) => HttpResponse::InternalServerError()
+                .json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                })),
+        },
+    }
+}
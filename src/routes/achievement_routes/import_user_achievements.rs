@@ -0,0 +1,44 @@
+use crate::{
+    AppState,
+    services::{
+        achievement_service::AchievementService,
+        errors::achievements::import_errors::ImportAchievementsError,
+    },
+};
+use actix_web::{HttpResponse, Responder, post, web};
+use serde_json::json;
+
+#[post("/{steam_id}/achievements/{appid}/import")]
+pub async fn import_user_achievements(
+    path: web::Path<(String, String)>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let (steam_id, appid) = path.into_inner();
+
+    match AchievementService::import_player_achievements(&data.db, &steam_id, &appid).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            ImportAchievementsError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            ImportAchievementsError::GameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found"
+            })),
+            ImportAchievementsError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "This user's game details are private"
+            })),
+            ImportAchievementsError::SteamApiError(msg) => HttpResponse::BadGateway().json(json!({
+                "status": "error",
+                "message": format!("Steam API error: {}", msg)
+            })),
+            ImportAchievementsError::DatabaseError(err) => HttpResponse::InternalServerError()
+                .json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                })),
+        },
+    }
+}
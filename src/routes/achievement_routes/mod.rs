@@ -0,0 +1,5 @@
+pub mod get_game_achievements;
+pub mod get_recent_achievements;
+pub mod get_user_achievements;
+pub mod import_game_achievements;
+pub mod import_user_achievements;
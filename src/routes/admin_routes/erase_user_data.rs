@@ -0,0 +1,30 @@
+use crate::{
+    AppState,
+    middleware::auth::Claims,
+    services::{errors::privacy::erase_errors::EraseUserError, privacy_service::PrivacyService},
+};
+
+use actix_web::{HttpResponse, Responder, post, web};
+use serde_json::json;
+
+// Hard erasure for privacy requests, unlike DELETE /api/users/{id} this cannot be restored
+#[post("/users/{steam_id}/erase")]
+async fn erase_user_data(
+    steam_id: web::Path<String>,
+    claims: web::ReqData<Claims>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match PrivacyService::erase_user(&data.db, &steam_id, &claims.sub).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            EraseUserError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            EraseUserError::DatabaseError(err) => HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": format!("Database error: {}", err)
+            })),
+        },
+    }
+}
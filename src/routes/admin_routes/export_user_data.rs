@@ -0,0 +1,63 @@
+use crate::{
+    AppState,
+    middleware::auth::Claims,
+    models::privacy::dto::{ExportFormat, ExportQueryParams},
+    services::{errors::privacy::export_errors::ExportUserError, privacy_service::PrivacyService},
+};
+
+use actix_web::{
+    HttpResponse, Responder, get,
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    web,
+};
+use serde_json::json;
+
+fn attachment(filename: String) -> ContentDisposition {
+    ContentDisposition {
+        disposition: DispositionType::Attachment,
+        parameters: vec![DispositionParam::Filename(filename)],
+    }
+}
+
+fn error_response(error: ExportUserError) -> HttpResponse {
+    match error {
+        ExportUserError::UserNotFound => HttpResponse::NotFound().json(json!({
+            "status": "error",
+            "message": "User not found"
+        })),
+        ExportUserError::ArchiveError(msg) => HttpResponse::InternalServerError().json(json!({
+            "status": "error",
+            "message": format!("Export error: {}", msg)
+        })),
+        ExportUserError::DatabaseError(err) => HttpResponse::InternalServerError().json(json!({
+            "status": "error",
+            "message": format!("Database error: {}", err)
+        })),
+    }
+}
+
+#[get("/users/{steam_id}/export")]
+async fn export_user_data(
+    steam_id: web::Path<String>,
+    params: web::Query<ExportQueryParams>,
+    claims: web::ReqData<Claims>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let export = match PrivacyService::export_user(&data.db, &steam_id, &claims.sub).await {
+        Ok(export) => export,
+        Err(e) => return error_response(e),
+    };
+
+    match params.format.unwrap_or_default() {
+        ExportFormat::Json => HttpResponse::Ok()
+            .insert_header(attachment(format!("user-{}-export.json", steam_id)))
+            .json(export),
+        ExportFormat::Zip => match PrivacyService::build_export_archive(&export) {
+            Ok(archive) => HttpResponse::Ok()
+                .content_type("application/zip")
+                .insert_header(attachment(format!("user-{}-export.zip", steam_id)))
+                .body(archive),
+            Err(e) => error_response(e),
+        },
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    AppState,
+    services::{
+        errors::steam_apps::import_errors::ImportSteamAppsError, steam_app_service::SteamAppService,
+    },
+};
+
+use actix_web::{HttpResponse, Responder, post, web};
+use serde_json::json;
+
+// Refreshes the local app catalog right away, outside of the daily job
+#[post("/steam-apps/import")]
+async fn import_steam_apps(data: web::Data<AppState>) -> impl Responder {
+    match SteamAppService::import_steam_apps(&data.db).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            ImportSteamAppsError::SteamApiError(msg) => HttpResponse::BadGateway().json(json!({
+                "status": "error",
+                "message": format!("Steam API error: {}", msg)
+            })),
+            ImportSteamAppsError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {:?}", err)
+                }))
+            }
+        },
+    }
+}
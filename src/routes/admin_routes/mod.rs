@@ -1 +1,6 @@
 pub mod create_admin;
+pub mod erase_user_data;
+pub mod export_user_data;
+pub mod import_steam_apps;
+pub mod restore_user;
+pub mod resync_game;
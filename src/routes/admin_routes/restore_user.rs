@@ -0,0 +1,30 @@
+use crate::{
+    AppState,
+    services::{errors::users::restore_errors::RestoreUserError, user_service::UserService},
+};
+
+use actix_web::{HttpResponse, Responder, post, web};
+use serde_json::json;
+
+// `id` is either the user's uuid or their Steam id
+#[post("/users/{id}/restore")]
+async fn restore_user(id: web::Path<String>, data: web::Data<AppState>) -> impl Responder {
+    match UserService::restore_user(&data.db, &id).await {
+        Ok(_) => HttpResponse::Ok().json(json!({
+            "status": "success",
+            "message": "User restored"
+        })),
+        Err(e) => match e {
+            RestoreUserError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Deleted user not found"
+            })),
+            RestoreUserError::DatabaseError(msg) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", msg)
+                }))
+            }
+        },
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{
+    AppState,
+    services::{errors::games::resync_errors::ResyncGameError, game_service::GameService},
+};
+
+use actix_web::{HttpResponse, Responder, post, web};
+use serde_json::json;
+
+// Refreshes a stored game from the store right away, outside of the sync job
+#[post("/games/{appid}/resync")]
+async fn resync_game(appid: web::Path<String>, data: web::Data<AppState>) -> impl Responder {
+    match GameService::resync_game(&data.db, &appid).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            ResyncGameError::GameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found"
+            })),
+            ResyncGameError::SteamGameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found on Steam"
+            })),
+            ResyncGameError::SteamApiError(msg) => HttpResponse::BadGateway().json(json!({
+                "status": "error",
+                "message": format!("Steam API error: {}", msg)
+            })),
+            ResyncGameError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {:?}", err)
+                }))
+            }
+        },
+    }
+}
@@ -1,14 +1,67 @@
-use super::admin_routes::create_admin::create_admin;
+use super::achievement_routes::{
+    get_game_achievements::get_game_achievements, get_recent_achievements::get_recent_achievements,
+    get_user_achievements::get_user_achievements,
+    import_game_achievements::import_game_achievements,
+    import_user_achievements::import_user_achievements,
+};
+use super::admin_routes::{
+    create_admin::create_admin, erase_user_data::erase_user_data,
+    export_user_data::export_user_data, import_steam_apps::import_steam_apps,
+    restore_user::restore_user, resync_game::resync_game,
+};
 use super::user_routes::{
-    create_user::create_user, delete_user::delete_user, get_user::get_user, get_users::get_users,
+    create_user::create_user, delete_user::delete_user,
+    get_recent_user_games::get_recent_user_games, get_user::get_user,
+    get_user_badges::get_user_badges, get_user_bans::get_user_bans, get_user_games::get_user_games,
+    get_user_playtime::get_user_playtime, get_user_profile::get_user_profile, get_users::get_users,
+    get_users_leaderboard::get_users_leaderboard, import_user_games::import_user_games,
+    refresh_user_bans::refresh_user_bans, refresh_user_progression::refresh_user_progression,
     update_user::update_user,
 };
 use actix_web::web;
 use actix_web_httpauth::middleware::HttpAuthentication;
 
 use super::auth_routes::login::login;
-use super::game_routes::{create_game::create_game, get_game::get_game};
-use super::inventory_items_routes::fetch_inventory::fetch_inventory;
+use super::friend_routes::{
+    get_friends::get_friends, get_friends_playing::get_friends_playing,
+    get_mutual_friends::get_mutual_friends, import_friends::import_friends,
+};
+use super::game_routes::{
+    create_game::create_game, get_game::get_game, get_game_changes::get_game_changes,
+    get_game_dlc::get_game_dlc, get_games::get_games,
+};
+use super::group_routes::{
+    get_group_members::get_group_members, get_user_groups::get_user_groups,
+    import_user_groups::import_user_groups,
+};
+use super::inventory_items_routes::{
+    fetch_inventory::fetch_inventory, get_user_items::get_user_items,
+};
+use super::news_routes::{
+    get_game_news::get_game_news, get_user_news::get_user_news,
+    import_game_news::import_game_news,
+};
+use super::player_count_routes::{
+    get_player_counts::get_player_counts, get_trending_games::get_trending_games,
+    import_player_count::import_player_count,
+};
+use super::price_routes::{
+    get_current_prices::get_current_prices, get_lowest_prices::get_lowest_prices,
+    get_price_history::get_price_history, import_game_prices::import_game_prices,
+};
+use super::review_routes::{
+    get_review_history::get_review_history, import_game_reviews::import_game_reviews,
+};
+use super::search_routes::search::search;
+use super::steam_app_routes::get_steam_apps::get_steam_apps;
+use super::watchlist_routes::{
+    add_admin_watchlist_item::add_admin_watchlist_item,
+    add_user_watchlist_item::add_user_watchlist_item,
+    get_admin_price_alerts::get_admin_price_alerts, get_admin_watchlist::get_admin_watchlist,
+    get_user_price_alerts::get_user_price_alerts, get_user_watchlist::get_user_watchlist,
+    remove_admin_watchlist_item::remove_admin_watchlist_item,
+    remove_user_watchlist_item::remove_user_watchlist_item,
+};
 use crate::middleware::auth::validator;
 
 pub fn config(conf: &mut web::ServiceConfig) {
@@ -20,20 +73,76 @@ pub fn config(conf: &mut web::ServiceConfig) {
         .service(create_user)
         .service(update_user)
         .service(get_user)
+        .service(get_user_profile)
         .service(get_users)
+        .service(get_users_leaderboard)
+        .service(get_user_games)
+        .service(get_recent_user_games)
+        .service(get_user_playtime)
+        .service(get_user_bans)
+        .service(refresh_user_bans)
+        .service(get_user_badges)
+        .service(refresh_user_progression)
+        .service(import_friends)
+        .service(get_friends)
+        .service(get_mutual_friends)
+        .service(get_friends_playing)
+        .service(import_user_groups)
+        .service(get_user_groups)
+        .service(get_user_items)
+        .service(import_user_achievements)
+        .service(get_user_achievements)
+        .service(get_recent_achievements)
+        .service(import_user_games)
+        .service(get_user_watchlist)
+        .service(get_user_price_alerts)
+        .service(get_user_news)
         .service(
             web::scope("")
                 .wrap(auth_middleware.clone())
-                .service(delete_user),
+                .service(delete_user)
+                .service(add_user_watchlist_item)
+                .service(remove_user_watchlist_item),
         );
 
     let games_scope = web::scope("/api/games")
+        .service(get_games)
+        .service(get_trending_games)
         .service(create_game)
-        .service(get_game);
+        .service(get_game)
+        .service(get_game_changes)
+        .service(get_game_dlc)
+        .service(import_game_achievements)
+        .service(get_game_achievements)
+        .service(import_game_prices)
+        .service(get_current_prices)
+        .service(get_lowest_prices)
+        .service(get_price_history)
+        .service(import_game_news)
+        .service(get_game_news)
+        .service(import_player_count)
+        .service(get_player_counts)
+        .service(import_game_reviews)
+        .service(get_review_history);
+
+    let groups_scope = web::scope("/api/groups").service(get_group_members);
+
+    let search_scope = web::scope("/api/search").service(search);
+
+    let steam_apps_scope = web::scope("/api/steam-apps").service(get_steam_apps);
 
     let admin_scope = web::scope("/api/admins")
         .wrap(auth_middleware)
-        .service(create_admin);
+        .service(create_admin)
+        .service(restore_user)
+        .service(export_user_data)
+        .service(erase_user_data)
+        .service(resync_game)
+        .service(import_steam_apps)
+        .service(get_admin_watchlist)
+        .service(add_admin_watchlist_item)
+        .service(remove_admin_watchlist_item)
+        .service(get_admin_price_alerts);
 
     let inventory_items_scope = web::scope("/api/inventory-items")
         .service(fetch_inventory);
@@ -42,5 +151,8 @@ pub fn config(conf: &mut web::ServiceConfig) {
     conf.service(users_scope);
     conf.service(admin_scope);
     conf.service(games_scope);
+    conf.service(groups_scope);
+    conf.service(search_scope);
+    conf.service(steam_apps_scope);
     conf.service(inventory_items_scope);
 }
@@ -0,0 +1,34 @@
+use crate::{
+    AppState,
+    models::user::viewer::Viewer,
+    services::{errors::friends::get_errors::GetFriendsError, friend_service::FriendService},
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{steam_id}/friends")]
+pub async fn get_friends(
+    steam_id: web::Path<String>,
+    data: web::Data<AppState>,
+    viewer: Viewer,
+) -> impl Responder {
+    match FriendService::get_friends(&data.db, &steam_id, viewer).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetFriendsError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            GetFriendsError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "Steam profile is private"
+            })),
+            GetFriendsError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                }))
+            }
+        },
+    }
+}
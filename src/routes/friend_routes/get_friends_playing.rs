@@ -0,0 +1,36 @@
+use crate::{
+    AppState,
+    models::user::viewer::Viewer,
+    services::{errors::friends::get_errors::GetFriendsError, friend_service::FriendService},
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{steam_id}/friends/playing/{appid}")]
+pub async fn get_friends_playing(
+    path: web::Path<(String, String)>,
+    data: web::Data<AppState>,
+    viewer: Viewer,
+) -> impl Responder {
+    let (steam_id, appid) = path.into_inner();
+
+    match FriendService::get_friends_playing(&data.db, &steam_id, &appid, viewer).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetFriendsError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            GetFriendsError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "Steam profile is private"
+            })),
+            GetFriendsError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                }))
+            }
+        },
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{
+    AppState,
+    models::user::viewer::Viewer,
+    services::{errors::friends::get_errors::GetFriendsError, friend_service::FriendService},
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{steam_id}/friends/mutual/{other_steam_id}")]
+pub async fn get_mutual_friends(
+    path: web::Path<(String, String)>,
+    data: web::Data<AppState>,
+    viewer: Viewer,
+) -> impl Responder {
+    let (steam_id, other_steam_id) = path.into_inner();
+
+    match FriendService::get_mutual_friends(&data.db, &steam_id, &other_steam_id, viewer).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetFriendsError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            GetFriendsError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "Steam profile is private"
+            })),
+            GetFriendsError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                }))
+            }
+        },
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    AppState,
+    models::friend::dto::ImportFriendsQueryParams,
+    services::{errors::friends::import_errors::ImportFriendsError, friend_service::FriendService},
+};
+use actix_web::{HttpResponse, Responder, post, web};
+use serde_json::json;
+
+#[post("/{steam_id}/friends/import")]
+pub async fn import_friends(
+    steam_id: web::Path<String>,
+    params: web::Query<ImportFriendsQueryParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match FriendService::import_friends(&data.db, &steam_id, params.register).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            ImportFriendsError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            ImportFriendsError::FriendListPrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "This user's friend list is private"
+            })),
+            ImportFriendsError::SteamApiError(msg) => HttpResponse::BadGateway().json(json!({
+                "status": "error",
+                "message": format!("Steam API error: {}", msg)
+            })),
+            ImportFriendsError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                }))
+            }
+        },
+    }
+}
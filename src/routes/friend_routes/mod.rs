@@ -0,0 +1,4 @@
+pub mod get_friends;
+pub mod get_friends_playing;
+pub mod get_mutual_friends;
+pub mod import_friends;
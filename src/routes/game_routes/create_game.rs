@@ -1,7 +1,10 @@
 use crate::{
     AppState,
     models::game::dto::CreateGameRequest,
-    services::{errors::games::create_errors::CreateGameError, game_service::GameService},
+    services::{
+        errors::games::create_errors::CreateGameError, game_service::GameService,
+        steam_app_service::SteamAppService,
+    },
 };
 use actix_web::{HttpResponse, Responder, post, web};
 use serde_json::json;
@@ -11,8 +14,12 @@ pub async fn create_game(
     body: web::Json<CreateGameRequest>,
     data: web::Data<AppState>,
 ) -> impl Responder {
-    let request = body.into_inner();
-    match GameService::create_game(&data.db, request.appid).await {
+    let created = match SteamAppService::resolve_appid(&data.db, body.into_inner()).await {
+        Ok(appid) => GameService::create_game(&data.db, appid).await,
+        Err(e) => Err(e),
+    };
+
+    match created {
         Ok(game_response) => HttpResponse::Ok().json(json!({
             "status": "success",
             "data": { "game": game_response }
@@ -26,6 +33,20 @@ pub async fn create_game(
                 "status": "error",
                 "message": "Steam game not found"
             })),
+            CreateGameError::MissingAppIdOrName => HttpResponse::BadRequest().json(json!({
+                "status": "error",
+                "message": "Either appid or name is required"
+            })),
+            CreateGameError::AppNameNotFound(candidates) => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "No Steam app has this name, retry with the appid of a candidate",
+                "candidates": candidates
+            })),
+            CreateGameError::AmbiguousAppName(candidates) => HttpResponse::Conflict().json(json!({
+                "status": "error",
+                "message": "Several Steam apps have this name, retry with the appid of a candidate",
+                "candidates": candidates
+            })),
             CreateGameError::SteamApiError(msg) => {
                 HttpResponse::InternalServerError().json(json!({
                     "status": "error",
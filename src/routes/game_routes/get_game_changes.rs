@@ -0,0 +1,34 @@
+use crate::{
+    AppState,
+    models::{
+        game::dto::GameChangesQueryParams,
+        pagination::constants::{MAX_PAGE_SIZE, MIN_PAGE_SIZE},
+    },
+    services::{errors::games::get_errors::GetGameError, game_service::GameService},
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+// Newest store changes first, as recorded by resyncs
+#[get("/{appid}/changes")]
+pub async fn get_game_changes(
+    appid: web::Path<String>,
+    params: web::Query<GameChangesQueryParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let limit = params.limit.clamp(MIN_PAGE_SIZE, MAX_PAGE_SIZE);
+
+    match GameService::get_game_changes(&data.db, &appid, limit).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetGameError::GameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found"
+            })),
+            GetGameError::DatabaseError(err) => HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": format!("Database error: {:?}", err)
+            })),
+        },
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    AppState,
+    models::game::dto::GameDlcQueryParams,
+    services::{errors::games::get_errors::GetGameError, game_service::GameService},
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+// DLC, demos and soundtracks of a stored game, `type` narrows them down
+#[get("/{appid}/dlc")]
+pub async fn get_game_dlc(
+    appid: web::Path<String>,
+    params: web::Query<GameDlcQueryParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match GameService::get_game_dlc(&data.db, &appid, params.app_type.as_deref()).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetGameError::GameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found"
+            })),
+            GetGameError::DatabaseError(err) => HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": format!("Database error: {:?}", err)
+            })),
+        },
+    }
+}
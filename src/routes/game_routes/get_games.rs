@@ -0,0 +1,47 @@
+use crate::{
+    AppState,
+    models::{
+        game::dto::GamesQueryParams,
+        pagination::{
+            PageRequest,
+            constants::{MAX_PAGE_SIZE, MIN_PAGE_SIZE},
+        },
+    },
+    services::{errors::games::list_errors::ListGamesError, game_service::GameService},
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("")]
+pub async fn get_games(
+    params: web::Query<GamesQueryParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let page_request = PageRequest {
+        limit: params.limit.clamp(MIN_PAGE_SIZE, MAX_PAGE_SIZE),
+        cursor: params.cursor.clone(),
+        include_total: params.include_total,
+    };
+
+    match GameService::get_games(
+        &data.db,
+        &params.filters(),
+        params.sort,
+        params.order,
+        &page_request,
+    )
+    .await
+    {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            ListGamesError::InvalidCursor => HttpResponse::BadRequest().json(json!({
+                "status": "error",
+                "message": "Invalid cursor"
+            })),
+            ListGamesError::DatabaseError(err) => HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": format!("Database error: {:?}", err)
+            })),
+        },
+    }
+}
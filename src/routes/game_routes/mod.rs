@@ -1,2 +1,5 @@
 pub mod create_game;
 pub mod get_game;
+pub mod get_game_changes;
+pub mod get_game_dlc;
+pub mod get_games;
@@ -0,0 +1,36 @@
+use crate::{
+    AppState,
+    models::user::viewer::Viewer,
+    services::{errors::groups::get_errors::GetGroupsError, group_service::GroupService},
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{steam_id}/groups")]
+pub async fn get_user_groups(
+    steam_id: web::Path<String>,
+    data: web::Data<AppState>,
+    viewer: Viewer,
+) -> impl Responder {
+    match GroupService::get_user_groups(&data.db, &steam_id, viewer).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetGroupsError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            GetGroupsError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "Steam profile is private"
+            })),
+            GetGroupsError::GroupNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Group not found"
+            })),
+            GetGroupsError::DatabaseError(err) => HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": format!("Database error: {}", err)
+            })),
+        },
+    }
+}
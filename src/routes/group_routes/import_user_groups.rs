@@ -0,0 +1,36 @@
+use crate::{
+    AppState,
+    services::{errors::groups::import_errors::ImportGroupsError, group_service::GroupService},
+};
+use actix_web::{HttpResponse, Responder, post, web};
+use serde_json::json;
+
+#[post("/{steam_id}/groups/import")]
+pub async fn import_user_groups(
+    steam_id: web::Path<String>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match GroupService::import_groups(&data.db, &steam_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            ImportGroupsError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            ImportGroupsError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "This user's group list is private"
+            })),
+            ImportGroupsError::SteamApiError(msg) => HttpResponse::BadGateway().json(json!({
+                "status": "error",
+                "message": format!("Steam API error: {}", msg)
+            })),
+            ImportGroupsError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                }))
+            }
+        },
+    }
+}
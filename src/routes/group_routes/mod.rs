@@ -0,0 +1,3 @@
+pub mod get_group_members;
+pub mod get_user_groups;
+pub mod import_user_groups;
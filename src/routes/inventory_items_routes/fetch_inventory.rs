@@ -29,7 +29,13 @@ pub async fn fetch_inventory(
             CreateInventoryItemError::InventoryNotFound => {
                 HttpResponse::NotFound().json(json!({
                     "status": "error",
-                    "message": "Steam inventory not found or is private"
+                    "message": "Steam inventory not found"
+                }))
+            }
+            CreateInventoryItemError::ProfilePrivate => {
+                HttpResponse::Forbidden().json(json!({
+                    "status": "error",
+                    "message": "Steam profile is private"
                 }))
             }
             CreateInventoryItemError::SteamApiError(msg) => {
@@ -0,0 +1,62 @@
+use crate::{
+    AppState,
+    models::{
+        inventory_items::dto::InventoryItemsQueryParams,
+        pagination::{
+            PageRequest,
+            constants::{MAX_PAGE_SIZE, MIN_PAGE_SIZE},
+        },
+        user::viewer::Viewer,
+    },
+    services::{
+        errors::inventory_items::list_errors::ListInventoryItemsError,
+        inventory_items_service::InventoryItemService,
+    },
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{steam_id}/inventory/items")]
+pub async fn get_user_items(
+    steam_id: web::Path<String>,
+    params: web::Query<InventoryItemsQueryParams>,
+    data: web::Data<AppState>,
+    viewer: Viewer,
+) -> impl Responder {
+    let page_request = PageRequest {
+        limit: params.limit.clamp(MIN_PAGE_SIZE, MAX_PAGE_SIZE),
+        cursor: params.cursor.clone(),
+        include_total: params.include_total,
+    };
+
+    match InventoryItemService::get_user_items(
+        &data.db,
+        &steam_id,
+        params.app_id.as_deref(),
+        &page_request,
+        viewer,
+    )
+    .await
+    {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            ListInventoryItemsError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            ListInventoryItemsError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "Steam profile is private"
+            })),
+            ListInventoryItemsError::InvalidCursor => HttpResponse::BadRequest().json(json!({
+                "status": "error",
+                "message": "Invalid cursor"
+            })),
+            ListInventoryItemsError::DatabaseError(err) => HttpResponse::InternalServerError()
+                .json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {:?}", err)
+                })),
+        },
+    }
+}
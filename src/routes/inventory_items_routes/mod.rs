@@ -1 +1,2 @@
 pub mod fetch_inventory;
+pub mod get_user_items;
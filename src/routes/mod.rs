@@ -4,4 +4,14 @@ pub mod health_route;
 pub mod user_routes;
 pub mod admin_routes;
 pub mod game_routes;
-pub mod inventory_items_routes;
\ No newline at end of file
+pub mod inventory_items_routes;
+pub mod friend_routes;
+pub mod achievement_routes;
+pub mod group_routes;
+pub mod search_routes;
+pub mod price_routes;
+pub mod watchlist_routes;
+pub mod steam_app_routes;
+pub mod news_routes;
+pub mod player_count_routes;
+pub mod review_routes;
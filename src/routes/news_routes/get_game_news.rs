@@ -0,0 +1,47 @@
+use crate::{
+    AppState,
+    models::{
+        news::dto::NewsQueryParams,
+        pagination::{
+            PageRequest,
+            constants::{MAX_PAGE_SIZE, MIN_PAGE_SIZE},
+        },
+    },
+    services::{errors::news::list_errors::ListGameNewsError, news_service::NewsService},
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{appid}/news")]
+pub async fn get_game_news(
+    appid: web::Path<String>,
+    params: web::Query<NewsQueryParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let page_request = PageRequest {
+        limit: params.limit.clamp(MIN_PAGE_SIZE, MAX_PAGE_SIZE),
+        cursor: params.cursor.clone(),
+        include_total: params.include_total,
+    };
+
+    match NewsService::get_game_news(&data.db, &appid, params.feed.as_deref(), &page_request).await
+    {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            ListGameNewsError::GameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found"
+            })),
+            ListGameNewsError::InvalidCursor => HttpResponse::BadRequest().json(json!({
+                "status": "error",
+                "message": "Invalid cursor"
+            })),
+            ListGameNewsError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {:?}", err)
+                }))
+            }
+        },
+    }
+}
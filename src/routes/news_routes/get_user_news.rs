@@ -0,0 +1,61 @@
+use crate::{
+    AppState,
+    models::{
+        news::dto::NewsQueryParams,
+        pagination::{
+            PageRequest,
+            constants::{MAX_PAGE_SIZE, MIN_PAGE_SIZE},
+        },
+        user::viewer::Viewer,
+    },
+    services::{errors::news::feed_errors::GetNewsFeedError, news_service::NewsService},
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+// Combined news of every game the user owns or played
+#[get("/{steam_id}/news")]
+pub async fn get_user_news(
+    steam_id: web::Path<String>,
+    params: web::Query<NewsQueryParams>,
+    data: web::Data<AppState>,
+    viewer: Viewer,
+) -> impl Responder {
+    let page_request = PageRequest {
+        limit: params.limit.clamp(MIN_PAGE_SIZE, MAX_PAGE_SIZE),
+        cursor: params.cursor.clone(),
+        include_total: params.include_total,
+    };
+
+    match NewsService::get_user_news(
+        &data.db,
+        &steam_id,
+        params.feed.as_deref(),
+        &page_request,
+        viewer,
+    )
+    .await
+    {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetNewsFeedError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            GetNewsFeedError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "Steam profile is private"
+            })),
+            GetNewsFeedError::InvalidCursor => HttpResponse::BadRequest().json(json!({
+                "status": "error",
+                "message": "Invalid cursor"
+            })),
+            GetNewsFeedError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {:?}", err)
+                }))
+            }
+        },
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{
+    AppState,
+    services::{errors::news::import_errors::ImportNewsError, news_service::NewsService},
+};
+use actix_web::{HttpResponse, Responder, post, web};
+use serde_json::json;
+
+#[post("/{appid}/news/import")]
+pub async fn import_game_news(
+    appid: web::Path<String>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match NewsService::import_game_news(&data.db, &appid).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            ImportNewsError::GameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found"
+            })),
+            ImportNewsError::SteamApiError(msg) => HttpResponse::BadGateway().json(json!({
+                "status": "error",
+                "message": format!("Steam API error: {}", msg)
+            })),
+            ImportNewsError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {:?}", err)
+                }))
+            }
+        },
+    }
+}
@@ -0,0 +1,3 @@
+pub mod get_game_news;
+pub mod get_user_news;
+pub mod import_game_news;
@@ -0,0 +1,45 @@
+use crate::{
+    AppState,
+    models::game::dto::PlayerCountsQueryParams,
+    services::{
+        errors::games::player_counts_errors::GetPlayerCountsError,
+        player_count_service::PlayerCountService,
+    },
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{appid}/players")]
+pub async fn get_player_counts(
+    appid: web::Path<String>,
+    params: web::Query<PlayerCountsQueryParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match PlayerCountService::get_player_counts(
+        &data.db,
+        &appid,
+        params.from,
+        params.to,
+        params.bucket,
+    )
+    .await
+    {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetPlayerCountsError::GameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found"
+            })),
+            GetPlayerCountsError::InvalidRange => HttpResponse::BadRequest().json(json!({
+                "status": "error",
+                "message": "`from` must not be after `to`"
+            })),
+            GetPlayerCountsError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {:?}", err)
+                }))
+            }
+        },
+    }
+}
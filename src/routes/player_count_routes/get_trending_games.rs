@@ -0,0 +1,28 @@
+use crate::{
+    AppState,
+    models::{
+        game::{constants::TRENDING_MAX_HOURS, dto::TrendingQueryParams},
+        pagination::constants::{MAX_PAGE_SIZE, MIN_PAGE_SIZE},
+    },
+    services::player_count_service::PlayerCountService,
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+// Games ranked by how much their average player count grew
+#[get("/trending")]
+pub async fn get_trending_games(
+    params: web::Query<TrendingQueryParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let hours = params.hours.clamp(1, TRENDING_MAX_HOURS);
+    let limit = params.limit.clamp(MIN_PAGE_SIZE, MAX_PAGE_SIZE);
+
+    match PlayerCountService::get_trending_games(&data.db, hours, params.min_players, limit).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(err) => HttpResponse::InternalServerError().json(json!({
+            "status": "error",
+            "message": format!("Database error: {:?}", err)
+        })),
+    }
+}
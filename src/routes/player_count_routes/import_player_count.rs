@@ -0,0 +1,35 @@
+use crate::{
+    AppState,
+    services::{
+        errors::games::import_player_count_errors::ImportPlayerCountError,
+        player_count_service::PlayerCountService,
+    },
+};
+use actix_web::{HttpResponse, Responder, post, web};
+use serde_json::json;
+
+#[post("/{appid}/players/import")]
+pub async fn import_player_count(
+    appid: web::Path<String>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match PlayerCountService::import_player_count(&data.db, &appid).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            ImportPlayerCountError::GameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found"
+            })),
+            ImportPlayerCountError::SteamApiError(msg) => HttpResponse::BadGateway().json(json!({
+                "status": "error",
+                "message": format!("Steam API error: {}", msg)
+            })),
+            ImportPlayerCountError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {:?}", err)
+                }))
+            }
+        },
+    }
+}
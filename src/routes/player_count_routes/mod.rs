@@ -0,0 +1,3 @@
+pub mod get_player_counts;
+pub mod get_trending_games;
+pub mod import_player_count;
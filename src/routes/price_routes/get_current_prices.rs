@@ -0,0 +1,28 @@
+use crate::{
+    AppState,
+    models::game::dto::PriceQueryParams,
+    services::{errors::games::get_errors::GetGameError, game_price_service::GamePriceService},
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{appid}/prices")]
+pub async fn get_current_prices(
+    appid: web::Path<String>,
+    params: web::Query<PriceQueryParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match GamePriceService::get_current_prices(&data.db, &appid, params.cc.as_deref()).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetGameError::GameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found"
+            })),
+            GetGameError::DatabaseError(err) => HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": format!("Database error: {:?}", err)
+            })),
+        },
+    }
+}
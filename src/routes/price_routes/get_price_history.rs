@@ -0,0 +1,36 @@
+use crate::{
+    AppState,
+    models::game::dto::PriceHistoryQueryParams,
+    services::{errors::games::get_errors::GetGameError, game_price_service::GamePriceService},
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{appid}/prices/history")]
+pub async fn get_price_history(
+    appid: web::Path<String>,
+    params: web::Query<PriceHistoryQueryParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match GamePriceService::get_price_history(
+        &data.db,
+        &appid,
+        params.cc.as_deref(),
+        params.from,
+        params.to,
+    )
+    .await
+    {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetGameError::GameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found"
+            })),
+            GetGameError::DatabaseError(err) => HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": format!("Database error: {:?}", err)
+            })),
+        },
+    }
+}
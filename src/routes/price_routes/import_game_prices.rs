@@ -0,0 +1,35 @@
+use crate::{
+    AppState,
+    services::{
+        errors::games::import_prices_errors::ImportPricesError,
+        game_price_service::GamePriceService,
+    },
+};
+use actix_web::{HttpResponse, Responder, post, web};
+use serde_json::json;
+
+#[post("/{appid}/prices/import")]
+pub async fn import_game_prices(
+    appid: web::Path<String>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match GamePriceService::import_game_prices(&data.db, &appid).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            ImportPricesError::GameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found"
+            })),
+            ImportPricesError::SteamApiError(msg) => HttpResponse::BadGateway().json(json!({
+                "status": "error",
+                "message": format!("Steam API error: {}", msg)
+            })),
+            ImportPricesError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {:?}", err)
+                }))
+            }
+        },
+    }
+}
@@ -0,0 +1,4 @@
+pub mod get_current_prices;
+pub mod get_lowest_prices;
+pub mod get_price_history;
+pub mod import_game_prices;
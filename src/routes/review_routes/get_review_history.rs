@@ -0,0 +1,28 @@
+use crate::{
+    AppState,
+    models::game::dto::ReviewHistoryQueryParams,
+    services::{errors::games::get_errors::GetGameError, game_review_service::GameReviewService},
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{appid}/reviews/history")]
+pub async fn get_review_history(
+    appid: web::Path<String>,
+    params: web::Query<ReviewHistoryQueryParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match GameReviewService::get_review_history(&data.db, &appid, params.from, params.to).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetGameError::GameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found"
+            })),
+            GetGameError::DatabaseError(err) => HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": format!("Database error: {:?}", err)
+            })),
+        },
+    }
+}
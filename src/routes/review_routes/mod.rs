@@ -0,0 +1,2 @@
+pub mod get_review_history;
+pub mod import_game_reviews;
@@ -0,0 +1,39 @@
+use crate::{
+    AppState,
+    models::{
+        search::{
+            constants::{MAX_SEARCH_LIMIT, MIN_SEARCH_LIMIT, MIN_SEARCH_QUERY_LENGTH},
+            dto::SearchQueryParams,
+        },
+        user::viewer::Viewer,
+    },
+    services::{errors::search::search_errors::SearchError, search_service::SearchService},
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("")]
+pub async fn search(
+    params: web::Query<SearchQueryParams>,
+    data: web::Data<AppState>,
+    viewer: Viewer,
+) -> impl Responder {
+    let limit = params.limit.clamp(MIN_SEARCH_LIMIT, MAX_SEARCH_LIMIT);
+
+    match SearchService::search(&data.db, &params.q, params.result_type, limit, viewer).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            SearchError::QueryTooShort => HttpResponse::BadRequest().json(json!({
+                "status": "error",
+                "message": format!(
+                    "Search query must have at least {} characters",
+                    MIN_SEARCH_QUERY_LENGTH
+                )
+            })),
+            SearchError::DatabaseError(err) => HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": format!("Database error: {}", err)
+            })),
+        },
+    }
+}
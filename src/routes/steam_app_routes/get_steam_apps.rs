@@ -0,0 +1,36 @@
+use crate::{
+    AppState,
+    models::{
+        search::constants::{MAX_SEARCH_LIMIT, MIN_SEARCH_LIMIT, MIN_SEARCH_QUERY_LENGTH},
+        steam_app::dto::SteamAppsQueryParams,
+    },
+    services::{errors::search::search_errors::SearchError, steam_app_service::SteamAppService},
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+// Typeahead over every Steam app, stored or not, to find the appid of a game by name
+#[get("")]
+pub async fn get_steam_apps(
+    params: web::Query<SteamAppsQueryParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let limit = params.limit.clamp(MIN_SEARCH_LIMIT, MAX_SEARCH_LIMIT);
+
+    match SteamAppService::search_steam_apps(&data.db, &params.q, limit).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            SearchError::QueryTooShort => HttpResponse::BadRequest().json(json!({
+                "status": "error",
+                "message": format!(
+                    "Search query must have at least {} characters",
+                    MIN_SEARCH_QUERY_LENGTH
+                )
+            })),
+            SearchError::DatabaseError(err) => HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": format!("Database error: {}", err)
+            })),
+        },
+    }
+}
@@ -22,6 +22,10 @@ pub async fn create_user(
                 "status": "error",
                 "message": "This user is already registered in the database!"
             })),
+            CreateUserError::UserDeleted => HttpResponse::Conflict().json(json!({
+                "status": "error",
+                "message": "This user was deleted, an admin can restore it"
+            })),
             CreateUserError::SteamUserNotFound => HttpResponse::NotFound().json(json!({
                 "status": "error",
                 "message": "Steam user not found"
@@ -5,11 +5,11 @@ use crate::{
 
 use actix_web::{HttpResponse, Responder, delete, web};
 use serde_json::json;
-use uuid::Uuid;
 
+// `id` is either the user's uuid or their Steam id
 #[delete("/user/{id}")]
-async fn delete_user(user_id: web::Path<Uuid>, data: web::Data<AppState>) -> impl Responder {
-    match UserService::delete_user(&data.db, user_id.into_inner()).await {
+async fn delete_user(id: web::Path<String>, data: web::Data<AppState>) -> impl Responder {
+    match UserService::delete_user(&data.db, &id).await {
         Ok(rows) => {
             if rows > 0 {
                 HttpResponse::NoContent().finish()
@@ -0,0 +1,34 @@
+use crate::AppState;
+use crate::models::user::viewer::Viewer;
+use crate::services::errors::user_games::get_errors::GetUserGamesError;
+use crate::services::user_games_service::UserGamesService;
+
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{steam_id}/games/recent")]
+async fn get_recent_user_games(
+    steam_id: web::Path<String>,
+    data: web::Data<AppState>,
+    viewer: Viewer,
+) -> impl Responder {
+    match UserGamesService::get_recent_user_games(&data.db, &steam_id, viewer).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetUserGamesError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            GetUserGamesError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "Steam profile is private"
+            })),
+            GetUserGamesError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                }))
+            }
+        },
+    }
+}
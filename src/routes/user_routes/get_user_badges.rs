@@ -0,0 +1,32 @@
+use crate::AppState;
+use crate::models::user::viewer::Viewer;
+use crate::services::errors::users::get_user::GetUserError;
+use crate::services::progression_service::ProgressionService;
+
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{steam_id}/badges")]
+async fn get_user_badges(
+    steam_id: web::Path<String>,
+    data: web::Data<AppState>,
+    viewer: Viewer,
+) -> impl Responder {
+    match ProgressionService::get_user_badges(&data.db, &steam_id, viewer).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetUserError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            GetUserError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "Steam profile is private"
+            })),
+            GetUserError::DatabaseError(msg) => HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": format!("Database error: {}", msg)
+            })),
+        },
+    }
+}
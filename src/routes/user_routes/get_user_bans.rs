@@ -0,0 +1,23 @@
+use crate::AppState;
+use crate::services::ban_service::BanService;
+use crate::services::errors::bans::get_errors::GetBansError;
+
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{steam_id}/bans")]
+async fn get_user_bans(steam_id: web::Path<String>, data: web::Data<AppState>) -> impl Responder {
+    match BanService::get_user_bans(&data.db, &steam_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetBansError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            GetBansError::DatabaseError(msg) => HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": format!("Database error: {}", msg)
+            })),
+        },
+    }
+}
@@ -0,0 +1,36 @@
+use crate::AppState;
+use crate::models::game::dto::UserGamesQueryParams;
+use crate::models::user::viewer::Viewer;
+use crate::services::errors::user_games::get_errors::GetUserGamesError;
+use crate::services::user_games_service::UserGamesService;
+
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{steam_id}/games")]
+async fn get_user_games(
+    steam_id: web::Path<String>,
+    params: web::Query<UserGamesQueryParams>,
+    data: web::Data<AppState>,
+    viewer: Viewer,
+) -> impl Responder {
+    match UserGamesService::get_user_games(&data.db, &steam_id, params.sort, viewer).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetUserGamesError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            GetUserGamesError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "Steam profile is private"
+            })),
+            GetUserGamesError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                }))
+            }
+        },
+    }
+}
@@ -0,0 +1,41 @@
+use crate::AppState;
+use crate::models::game::dto::PlaytimeQueryParams;
+use crate::models::user::viewer::Viewer;
+use crate::services::errors::user_games::get_playtime_errors::GetPlaytimeError;
+use crate::services::user_games_service::UserGamesService;
+
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{steam_id}/playtime")]
+async fn get_user_playtime(
+    steam_id: web::Path<String>,
+    params: web::Query<PlaytimeQueryParams>,
+    data: web::Data<AppState>,
+    viewer: Viewer,
+) -> impl Responder {
+    match UserGamesService::get_playtime(&data.db, &steam_id, params.from, params.to, viewer).await
+    {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetPlaytimeError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            GetPlaytimeError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "Steam profile is private"
+            })),
+            GetPlaytimeError::InvalidRange => HttpResponse::BadRequest().json(json!({
+                "status": "error",
+                "message": "`from` must not be after `to`"
+            })),
+            GetPlaytimeError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                }))
+            }
+        },
+    }
+}
@@ -0,0 +1,45 @@
+use crate::AppState;
+use crate::models::profile::dto::ProfileQueryParams;
+use crate::models::user::viewer::Viewer;
+use crate::services::errors::profile::get_errors::GetProfileError;
+use crate::services::profile_service::ProfileService;
+
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{steam_id}/profile")]
+async fn get_user_profile(
+    steam_id: web::Path<String>,
+    params: web::Query<ProfileQueryParams>,
+    data: web::Data<AppState>,
+    viewer: Viewer,
+) -> impl Responder {
+    match ProfileService::get_user_profile(&data.db, &steam_id, params.include.as_deref(), viewer)
+        .await
+    {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetProfileError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            GetProfileError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "Steam profile is private"
+            })),
+            GetProfileError::InvalidSection(section) => HttpResponse::BadRequest().json(json!({
+                "status": "error",
+                "message": format!(
+                    "Unknown profile section '{}', expected user, games, inventory or sync",
+                    section
+                )
+            })),
+            GetProfileError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                }))
+            }
+        },
+    }
+}
@@ -1,22 +1,50 @@
 use crate::AppState;
-use crate::models::user::constants::{MAX_PAGE_SIZE, MIN_PAGE_SIZE};
+use crate::models::pagination::PageRequest;
+use crate::models::pagination::constants::{MAX_PAGE_SIZE, MIN_PAGE_SIZE};
 use crate::models::user::pagination::user_pagination::QueryParams;
+use crate::models::user::viewer::Viewer;
+use crate::services::errors::users::get_users::GetUsersError;
 use crate::services::user_service::UserService;
 use actix_web::{HttpResponse, get, web};
 use serde_json::json;
 
 #[get("")]
-pub async fn get_users(data: web::Data<AppState>, params: web::Query<QueryParams>) -> HttpResponse {
+pub async fn get_users(
+    data: web::Data<AppState>,
+    params: web::Query<QueryParams>,
+    viewer: Viewer,
+) -> HttpResponse {
     let page = params.page.max(1);
     let limit = params.limit.clamp(MIN_PAGE_SIZE, MAX_PAGE_SIZE);
 
-    let username_filter = params.username.as_deref();
+    let filters = params.filters(viewer);
+    let page_request = PageRequest {
+        limit,
+        cursor: params.cursor.clone(),
+        include_total: params.include_total,
+    };
 
-    match UserService::get_users(&data.db, username_filter, page, limit).await {
+    match UserService::get_users(
+        &data.db,
+        &filters,
+        params.sort,
+        params.order,
+        page,
+        &page_request,
+        viewer,
+    )
+    .await
+    {
         Ok(response) => HttpResponse::Ok().json(response),
-        Err(_e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": "Failed to fetch users"
-        })),
+        Err(e) => match e {
+            GetUsersError::InvalidCursor => HttpResponse::BadRequest().json(json!({
+                "status": "error",
+                "message": "Invalid cursor"
+            })),
+            GetUsersError::DatabaseError(err) => HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": format!("Failed to fetch users: {}", err)
+            })),
+        },
     }
 }
@@ -0,0 +1,26 @@
+use crate::AppState;
+use crate::models::progression::constants::{MAX_LEADERBOARD_LIMIT, MIN_LEADERBOARD_LIMIT};
+use crate::models::progression::dto::LeaderboardQueryParams;
+use crate::models::user::viewer::Viewer;
+use crate::services::progression_service::ProgressionService;
+use actix_web::{HttpResponse, get, web};
+use serde_json::json;
+
+#[get("/leaderboard")]
+pub async fn get_users_leaderboard(
+    data: web::Data<AppState>,
+    params: web::Query<LeaderboardQueryParams>,
+    viewer: Viewer,
+) -> HttpResponse {
+    let limit = params
+        .limit
+        .clamp(MIN_LEADERBOARD_LIMIT, MAX_LEADERBOARD_LIMIT);
+
+    match ProgressionService::get_leaderboard(&data.db, params.sort, limit, viewer).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(_e) => HttpResponse::InternalServerError().json(json!({
+            "status": "error",
+            "message": "Failed to fetch leaderboard"
+        })),
+    }
+}
@@ -0,0 +1,32 @@
+use crate::AppState;
+use crate::services::errors::user_games::import_errors::ImportUserGamesError;
+use crate::services::user_games_service::UserGamesService;
+
+use actix_web::{HttpResponse, Responder, post, web};
+use serde_json::json;
+
+#[post("/{steam_id}/games/import")]
+async fn import_user_games(
+    steam_id: web::Path<String>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match UserGamesService::import_owned_games(&data.db, &steam_id).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            ImportUserGamesError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            ImportUserGamesError::SteamApiError(msg) => HttpResponse::BadGateway().json(json!({
+                "status": "error",
+                "message": format!("Steam API error: {}", msg)
+            })),
+            ImportUserGamesError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                }))
+            }
+        },
+    }
+}
@@ -3,3 +3,14 @@ pub mod update_user;
 pub mod get_user;
 pub mod get_users;
 pub mod create_user;
+
+pub mod get_user_games;
+pub mod import_user_games;
+pub mod get_recent_user_games;
+pub mod get_user_playtime;
+pub mod get_user_bans;
+pub mod refresh_user_bans;
+pub mod get_user_badges;
+pub mod refresh_user_progression;
+pub mod get_users_leaderboard;
+pub mod get_user_profile;
@@ -0,0 +1,39 @@
+use crate::AppState;
+use crate::services::ban_service::BanService;
+use crate::services::errors::bans::refresh_errors::RefreshBansError;
+
+use actix_web::{HttpResponse, Responder, post, web};
+use serde_json::json;
+
+#[post("/{steam_id}/bans/refresh")]
+async fn refresh_user_bans(
+    steam_id: web::Path<String>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match BanService::refresh_bans(&data.db, &steam_id).await {
+        Ok(bans) => HttpResponse::Ok().json(json!({
+            "status": "success",
+            "data": { "bans": bans }
+        })),
+        Err(e) => match e {
+            RefreshBansError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            RefreshBansError::SteamUserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Steam user not found"
+            })),
+            RefreshBansError::SteamApiError(msg) => HttpResponse::BadGateway().json(json!({
+                "status": "error",
+                "message": format!("Steam API error: {}", msg)
+            })),
+            RefreshBansError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                }))
+            }
+        },
+    }
+}
@@ -0,0 +1,38 @@
+use crate::AppState;
+use crate::services::errors::progression::refresh_errors::RefreshProgressionError;
+use crate::services::progression_service::ProgressionService;
+
+use actix_web::{HttpResponse, Responder, post, web};
+use serde_json::json;
+
+#[post("/{steam_id}/progression/refresh")]
+async fn refresh_user_progression(
+    steam_id: web::Path<String>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    match ProgressionService::refresh_progression(&data.db, &steam_id).await {
+        Ok(progression) => HttpResponse::Ok().json(json!({
+            "status": "success",
+            "data": { "progression": progression }
+        })),
+        Err(e) => match e {
+            RefreshProgressionError::UserNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            RefreshProgressionError::ProfilePrivate => HttpResponse::Forbidden().json(json!({
+                "status": "error",
+                "message": "Steam profile is private"
+            })),
+            RefreshProgressionError::SteamApiError(msg) => HttpResponse::BadGateway().json(json!({
+                "status": "error",
+                "message": format!("Steam API error: {}", msg)
+            })),
+            RefreshProgressionError::DatabaseError(err) => HttpResponse::InternalServerError()
+                .json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {}", err)
+                })),
+        },
+    }
+}
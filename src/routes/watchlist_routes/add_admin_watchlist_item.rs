@@ -0,0 +1,51 @@
+use crate::{
+    AppState,
+    middleware::auth::Claims,
+    models::watchlist::{dto::AddWatchlistItemRequest, watchlist_owner::WatchlistOwner},
+    services::{
+        errors::watchlists::add_errors::AddWatchlistItemError, watchlist_service::WatchlistService,
+    },
+};
+use actix_web::{HttpResponse, Responder, post, web};
+use serde_json::json;
+
+#[post("/watchlist")]
+pub async fn add_admin_watchlist_item(
+    claims: web::ReqData<Claims>,
+    body: web::Json<AddWatchlistItemRequest>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let owner = WatchlistOwner::Admin(claims.sub.clone());
+
+    match WatchlistService::add_item(&data.db, &owner, body.into_inner()).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            AddWatchlistItemError::OwnerNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Admin not found"
+            })),
+            AddWatchlistItemError::GameNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Game not found"
+            })),
+            AddWatchlistItemError::UntrackedRegion => HttpResponse::BadRequest().json(json!({
+                "status": "error",
+                "message": "Prices are not tracked for this region"
+            })),
+            AddWatchlistItemError::InvalidThreshold => HttpResponse::BadRequest().json(json!({
+                "status": "error",
+                "message": "Set a target_price of at least 0 or a discount_threshold between 1 and 100"
+            })),
+            AddWatchlistItemError::SteamApiError(msg) => HttpResponse::BadGateway().json(json!({
+                "status": "error",
+                "message": format!("Steam API error: {}", msg)
+            })),
+            AddWatchlistItemError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {:?}", err)
+                }))
+            }
+        },
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{
+    AppState,
+    middleware::auth::Claims,
+    models::watchlist::watchlist_owner::WatchlistOwner,
+    services::{
+        errors::watchlists::get_errors::GetWatchlistError, watchlist_service::WatchlistService,
+    },
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/watchlist")]
+pub async fn get_admin_watchlist(
+    claims: web::ReqData<Claims>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let owner = WatchlistOwner::Admin(claims.sub.clone());
+
+    match WatchlistService::get_watchlist(&data.db, &owner).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetWatchlistError::OwnerNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Admin not found"
+            })),
+            GetWatchlistError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {:?}", err)
+                }))
+            }
+        },
+    }
+}
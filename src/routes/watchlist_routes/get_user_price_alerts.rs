@@ -0,0 +1,38 @@
+use crate::{
+    AppState,
+    models::{
+        pagination::constants::{MAX_PAGE_SIZE, MIN_PAGE_SIZE},
+        watchlist::{dto::PriceAlertsQueryParams, watchlist_owner::WatchlistOwner},
+    },
+    services::{
+        errors::watchlists::get_errors::GetWatchlistError, watchlist_service::WatchlistService,
+    },
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{steam_id}/price-alerts")]
+pub async fn get_user_price_alerts(
+    steam_id: web::Path<String>,
+    params: web::Query<PriceAlertsQueryParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let owner = WatchlistOwner::User(steam_id.into_inner());
+    let limit = params.limit.clamp(MIN_PAGE_SIZE, MAX_PAGE_SIZE);
+
+    match WatchlistService::get_alerts(&data.db, &owner, limit).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetWatchlistError::OwnerNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            GetWatchlistError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {:?}", err)
+                }))
+            }
+        },
+    }
+}
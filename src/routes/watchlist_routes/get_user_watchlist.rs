@@ -0,0 +1,33 @@
+use crate::{
+    AppState,
+    models::watchlist::watchlist_owner::WatchlistOwner,
+    services::{
+        errors::watchlists::get_errors::GetWatchlistError, watchlist_service::WatchlistService,
+    },
+};
+use actix_web::{HttpResponse, Responder, get, web};
+use serde_json::json;
+
+#[get("/{steam_id}/watchlist")]
+pub async fn get_user_watchlist(
+    steam_id: web::Path<String>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let owner = WatchlistOwner::User(steam_id.into_inner());
+
+    match WatchlistService::get_watchlist(&data.db, &owner).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            GetWatchlistError::OwnerNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            GetWatchlistError::DatabaseError(err) => {
+                HttpResponse::InternalServerError().json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {:?}", err)
+                }))
+            }
+        },
+    }
+}
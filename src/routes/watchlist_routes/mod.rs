@@ -0,0 +1,8 @@
+pub mod add_admin_watchlist_item;
+pub mod add_user_watchlist_item;
+pub mod get_admin_price_alerts;
+pub mod get_admin_watchlist;
+pub mod get_user_price_alerts;
+pub mod get_user_watchlist;
+pub mod remove_admin_watchlist_item;
+pub mod remove_user_watchlist_item;
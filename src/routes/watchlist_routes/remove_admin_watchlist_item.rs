@@ -0,0 +1,42 @@
+use crate::{
+    AppState,
+    middleware::auth::Claims,
+    models::watchlist::{dto::RemoveWatchlistItemParams, watchlist_owner::WatchlistOwner},
+    services::{
+        errors::watchlists::remove_errors::RemoveWatchlistItemError,
+        watchlist_service::WatchlistService,
+    },
+};
+use actix_web::{HttpResponse, Responder, delete, web};
+use serde_json::json;
+
+#[delete("/watchlist/{appid}")]
+pub async fn remove_admin_watchlist_item(
+    appid: web::Path<String>,
+    params: web::Query<RemoveWatchlistItemParams>,
+    claims: web::ReqData<Claims>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let owner = WatchlistOwner::Admin(claims.sub.clone());
+
+    match WatchlistService::remove_item(&data.db, &owner, &appid, params.cc.as_deref()).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            RemoveWatchlistItemError::OwnerNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "Admin not found"
+            })),
+            RemoveWatchlistItemError::WatchlistItemNotFound => {
+                HttpResponse::NotFound().json(json!({
+                    "status": "error",
+                    "message": "Game is not on the watchlist"
+                }))
+            }
+            RemoveWatchlistItemError::DatabaseError(err) => HttpResponse::InternalServerError()
+                .json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {:?}", err)
+                })),
+        },
+    }
+}
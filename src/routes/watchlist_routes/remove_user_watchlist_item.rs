@@ -0,0 +1,41 @@
+use crate::{
+    AppState,
+    models::watchlist::{dto::RemoveWatchlistItemParams, watchlist_owner::WatchlistOwner},
+    services::{
+        errors::watchlists::remove_errors::RemoveWatchlistItemError,
+        watchlist_service::WatchlistService,
+    },
+};
+use actix_web::{HttpResponse, Responder, delete, web};
+use serde_json::json;
+
+#[delete("/{steam_id}/watchlist/{appid}")]
+pub async fn remove_user_watchlist_item(
+    path: web::Path<(String, String)>,
+    params: web::Query<RemoveWatchlistItemParams>,
+    data: web::Data<AppState>,
+) -> impl Responder {
+    let (steam_id, appid) = path.into_inner();
+    let owner = WatchlistOwner::User(steam_id);
+
+    match WatchlistService::remove_item(&data.db, &owner, &appid, params.cc.as_deref()).await {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(e) => match e {
+            RemoveWatchlistItemError::OwnerNotFound => HttpResponse::NotFound().json(json!({
+                "status": "error",
+                "message": "User not found"
+            })),
+            RemoveWatchlistItemError::WatchlistItemNotFound => {
+                HttpResponse::NotFound().json(json!({
+                    "status": "error",
+                    "message": "Game is not on the watchlist"
+                }))
+            }
+            RemoveWatchlistItemError::DatabaseError(err) => HttpResponse::InternalServerError()
+                .json(json!({
+                    "status": "error",
+                    "message": format!("Database error: {:?}", err)
+                })),
+        },
+    }
+}
@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use sqlx::PgPool;
+
+use crate::{
+    models::{
+        ResponseStatus,
+        achievement::{
+            constants::RARE_ACHIEVEMENT_PERCENT,
+            dto::{
+                GetAchievementCompletionResponse, GetGameAchievementsResponse,
+                GetRecentAchievementsResponse, ImportAchievementsResponse, UpsertAchievementSchema,
+                UpsertUserAchievementSchema,
+            },
+        },
+        user::viewer::Viewer,
+    },
+    repositories::{
+        achievement_repository::AchievementRepository, game_repository::GameRepository,
+        user_repository::UserRepository,
+    },
+    services::errors::{
+        achievements::{get_errors::GetAchievementsError, import_errors::ImportAchievementsError},
+        games::get_errors::GetGameError,
+    },
+    steam::steam_achievements_response::{
+        SteamGameSchemaResponse, SteamGlobalAchievementPercentagesResponse,
+        SteamPlayerAchievementsResponse,
+    },
+};
+
+pub struct AchievementService;
+
+impl AchievementService {
+    async fn fetch_user_stats<T: serde::de::DeserializeOwned>(
+        method: &str,
+        params: &str,
+    ) -> Result<T, ImportAchievementsError> {
+        dotenv::dotenv().ok();
+        let key = std::env::var("STEAM_KEY").map_err(|_| {
+            ImportAchievementsError::SteamApiError("Steam API Key not found".to_string())
+        })?;
+
+        let steam_api = format!(
+            "http://api.steampowered.com/ISteamUserStats/{}/?key={}&{}&l=english&format=json",
+            method, key, params
+        );
+
+        let response = reqwest::get(&steam_api).await.map_err(|e| {
+            ImportAchievementsError::SteamApiError(format!("Failed to fetch: {:?}", e))
+        })?;
+
+        // Error statuses still carry a JSON body explaining what went wrong
+        response.json().await.map_err(|e| {
+            ImportAchievementsError::SteamApiError(format!("Failed to parse: {:?}", e))
+        })
+    }
+
+    pub async fn import_game_schema(
+        pool: &PgPool,
+        appid: &str,
+    ) -> Result<ImportAchievementsResponse, ImportAchievementsError> {
+        let existing_game = GameRepository::check_if_game_exists(pool, appid).await?;
+        if !existing_game {
+            return Err(ImportAchievementsError::GameNotFound);
+        }
+
+        let game = GameRepository::get_game_by_appid(pool, appid).await?;
+
+        let schema = Self::fetch_user_stats::<SteamGameSchemaResponse>(
+            "GetSchemaForGame/v2",
+            &format!("appid={}", appid),
+        )
+        .await?;
+
+        let achievements = schema
+            .game
+            .available_game_stats
+            .map(|stats| stats.achievements)
+            .unwrap_or_default();
+
+        for achievement in &achievements {
+            AchievementRepository::upsert_achievement(
+                pool,
+                UpsertAchievementSchema {
+                    game_id: game.id,
+                    api_name: achievement.name.clone(),
+                    display_name: achievement.display_name.clone(),
+                    description: achievement.description.clone(),
+                    icon: achievement.icon.clone(),
+                    icon_gray: achievement.icongray.clone(),
+                    hidden: achievement.hidden == 1,
+                },
+            )
+            .await?;
+        }
+
+        if !achievements.is_empty() {
+            Self::import_global_percentages(pool, game.id, appid).await?;
+        }
+
+        Ok(ImportAchievementsResponse {
+            status: ResponseStatus::Success,
+            imported: achievements.len(),
+        })
+    }
+
+    async fn import_global_percentages(
+        pool: &PgPool,
+        game_id: uuid::Uuid,
+        appid: &str,
+    ) -> Result<(), ImportAchievementsError> {
+        let percentages = Self::fetch_user_stats::<SteamGlobalAchievementPercentagesResponse>(
+            "GetGlobalAchievementPercentagesForApp/v0002",
+            &format!("gameid={}", appid),
+        )
+        .await?
+        .achievementpercentages
+        .achievements;
+
+        for percentage in percentages {
+            AchievementRepository::update_global_percent(
+                pool,
+                game_id,
+                &percentage.name,
+                percentage.percent,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn import_player_achievements(
+        pool: &PgPool,
+        steam_id: &str,
+        appid: &str,
+    ) -> Result<ImportAchievementsResponse, ImportAchievementsError> {
+        let existing_user = UserRepository::check_if_user_exits(pool, steam_id).await?;
+        if !existing_user {
+            return Err(ImportAchievementsError::UserNotFound);
+        }
+
+        let existing_game = GameRepository::check_if_game_exists(pool, appid).await?;
+        if !existing_game {
+            return Err(ImportAchievementsError::GameNotFound);
+        }
+
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+        let game = GameRepository::get_game_by_appid(pool, appid).await?;
+
+        let mut achievements = AchievementRepository::get_game_achievements(pool, game.id).await?;
+        if achievements.is_empty() {
+            Self::import_game_schema(pool, appid).await?;
+            achievements = AchievementRepository::get_game_achievements(pool, game.id).await?;
+        }
+
+        let achievement_ids: HashMap<String, uuid::Uuid> = achievements
+            .into_iter()
+            .map(|achievement| (achievement.api_name, achievement.id))
+            .collect();
+
+        let player_stats = Self::fetch_user_stats::<SteamPlayerAchievementsResponse>(
+            "GetPlayerAchievements/v0001",
+            &format!("steamid={}&appid={}", steam_id, appid),
+        )
+        .await?
+        .playerstats;
+
+        if !player_stats.success {
+            let message = player_stats.error.unwrap_or_default();
+            if message.contains("not public") {
+                return Err(ImportAchievementsError::ProfilePrivate);
+            }
+            return Err(ImportAchievementsError::SteamApiError(message));
+        }
+
+        let mut imported = 0;
+
+        for achievement in player_stats.achievements {
+            if achievement.achieved != 1 {
+                continue;
+            }
+
+            // Unlocks for achievements missing from the stored schema are skipped
+            let Some(achievement_id) = achievement_ids.get(&achievement.apiname) else {
+                continue;
+            };
+
+            let Some(unlocked_at) = DateTime::from_timestamp(achievement.unlocktime, 0) else {
+                continue;
+            };
+
+            AchievementRepository::upsert_user_achievement(
+                pool,
+                UpsertUserAchievementSchema {
+                    user_id,
+                    achievement_id: *achievement_id,
+                    unlocked_at,
+                },
+            )
+            .await?;
+
+            imported += 1;
+        }
+
+        Ok(ImportAchievementsResponse {
+            status: ResponseStatus::Success,
+            imported,
+        })
+    }
+
+    pub async fn get_user_completion(
+        pool: &PgPool,
+        steam_id: &str,
+        viewer: Viewer,
+    ) -> Result<GetAchievementCompletionResponse, GetAchievementsError> {
+        let visibility = UserRepository::get_visibility(pool, steam_id).await?;
+        if !viewer.can_view(visibility) {
+            return Err(GetAchievementsError::ProfilePrivate);
+        }
+
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let games = AchievementRepository::get_user_completion(pool, user_id).await?;
+
+        Ok(GetAchievementCompletionResponse {
+            status: ResponseStatus::Success,
+            total: games.len(),
+            rarity_score: games.iter().map(|game| game.rarity_score).sum(),
+            games,
+        })
+    }
+
+    pub async fn get_recent_achievements(
+        pool: &PgPool,
+        steam_id: &str,
+        limit: i64,
+        viewer: Viewer,
+    ) -> Result<GetRecentAchievementsResponse, GetAchievementsError> {
+        let visibility = UserRepository::get_visibility(pool, steam_id).await?;
+        if !viewer.can_view(visibility) {
+            return Err(GetAchievementsError::ProfilePrivate);
+        }
+
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let achievements = AchievementRepository::get_recent_user_achievements(
+            pool,
+            user_id,
+            limit,
+            RARE_ACHIEVEMENT_PERCENT,
+        )
+        .await?;
+
+        Ok(GetRecentAchievementsResponse {
+            status: ResponseStatus::Success,
+            achievements,
+        })
+    }
+
+    pub async fn get_game_achievements(
+        pool: &PgPool,
+        appid: &str,
+    ) -> Result<GetGameAchievementsResponse, GetGameError> {
+        let game = GameRepository::get_game_by_appid(pool, appid).await?;
+
+        let achievements = AchievementRepository::get_game_achievement_stats(
+            pool,
+            game.id,
+            RARE_ACHIEVEMENT_PERCENT,
+        )
+        .await?;
+
+        Ok(GetGameAchievementsResponse {
+            status: ResponseStatus::Success,
+            appid: game.appid,
+            total: achievements.len(),
+            achievements,
+        })
+    }
+}
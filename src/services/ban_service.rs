@@ -0,0 +1,112 @@
+use sqlx::PgPool;
+
+use crate::{
+    models::{
+        ResponseStatus,
+        ban::{
+            ban::UserBanModel,
+            dto::{GetUserBansResponse, UpsertUserBanSchema},
+        },
+    },
+    repositories::{ban_repository::BanRepository, user_repository::UserRepository},
+    services::errors::bans::{get_errors::GetBansError, refresh_errors::RefreshBansError},
+    steam::steam_player_bans_response::SteamPlayerBansResponse,
+};
+
+pub struct BanService;
+
+impl BanService {
+    async fn fetch_player_bans(
+        steam_id: &str,
+    ) -> Result<SteamPlayerBansResponse, RefreshBansError> {
+        dotenv::dotenv().ok();
+        let key = std::env::var("STEAM_KEY")
+            .map_err(|_| RefreshBansError::SteamApiError("Steam API Key not found".to_string()))?;
+
+        let steam_api = format!(
+            "http://api.steampowered.com/ISteamUser/GetPlayerBans/v1/?key={}&steamids={}&format=json",
+            key, steam_id
+        );
+
+        let response = reqwest::get(&steam_api)
+            .await
+            .map_err(|e| RefreshBansError::SteamApiError(format!("Failed to fetch: {:?}", e)))?;
+
+        response
+            .json()
+            .await
+            .map_err(|e| RefreshBansError::SteamApiError(format!("Failed to parse: {:?}", e)))
+    }
+
+    fn has_changed(current: &UserBanModel, incoming: &UpsertUserBanSchema) -> bool {
+        // days_since_last_ban grows every day, so it is not treated as a change
+        current.vac_banned != incoming.vac_banned
+            || current.number_of_vac_bans != incoming.number_of_vac_bans
+            || current.number_of_game_bans != incoming.number_of_game_bans
+            || current.community_banned != incoming.community_banned
+            || current.economy_ban != incoming.economy_ban
+    }
+
+    pub async fn refresh_bans(
+        pool: &PgPool,
+        steam_id: &str,
+    ) -> Result<UserBanModel, RefreshBansError> {
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let player_bans = Self::fetch_player_bans(steam_id)
+            .await?
+            .players
+            .into_iter()
+            .next()
+            .ok_or(RefreshBansError::SteamUserNotFound)?;
+
+        let upsert_schema = UpsertUserBanSchema {
+            user_id,
+            vac_banned: player_bans.vac_banned,
+            number_of_vac_bans: player_bans.number_of_vac_bans,
+            days_since_last_ban: player_bans.days_since_last_ban,
+            number_of_game_bans: player_bans.number_of_game_bans,
+            community_banned: player_bans.community_banned,
+            economy_ban: player_bans.economy_ban,
+        };
+
+        // The history entry and the new status are written together
+        let mut tx = pool.begin().await?;
+
+        let changed = match BanRepository::get_user_ban(&mut *tx, user_id).await? {
+            Some(current) => Self::has_changed(&current, &upsert_schema),
+            None => true,
+        };
+
+        if changed {
+            BanRepository::create_ban_history(&mut *tx, upsert_schema.clone()).await?;
+        }
+
+        let user_ban = BanRepository::upsert_user_ban(&mut *tx, upsert_schema).await?;
+
+        tx.commit().await?;
+
+        Ok(user_ban)
+    }
+
+    pub async fn get_user_bans(
+        pool: &PgPool,
+        steam_id: &str,
+    ) -> Result<GetUserBansResponse, GetBansError> {
+        let existing_user = UserRepository::check_if_user_exits(pool, steam_id).await?;
+        if !existing_user {
+            return Err(GetBansError::UserNotFound);
+        }
+
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let bans = BanRepository::get_ban_status_by_steam_id(pool, steam_id).await?;
+        let history = BanRepository::get_ban_history(pool, user_id).await?;
+
+        Ok(GetUserBansResponse {
+            status: ResponseStatus::Success,
+            bans,
+            history,
+        })
+    }
+}
@@ -0,0 +1,15 @@
+#[derive(Debug)]
+pub enum GetAchievementsError {
+    UserNotFound,
+    ProfilePrivate,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for GetAchievementsError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => GetAchievementsError::UserNotFound,
+            _ => GetAchievementsError::DatabaseError(error),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+#[derive(Debug)]
+pub enum ImportAchievementsError {
+    UserNotFound,
+    GameNotFound,
+    ProfilePrivate,
+    SteamApiError(String),
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ImportAchievementsError {
+    fn from(error: sqlx::Error) -> Self {
+        ImportAchievementsError::DatabaseError(error)
+    }
+}
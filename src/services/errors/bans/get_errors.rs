@@ -0,0 +1,15 @@
+// Ban status is public on Steam, so it is served for private profiles too
+#[derive(Debug)]
+pub enum GetBansError {
+    UserNotFound,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for GetBansError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => GetBansError::UserNotFound,
+            _ => GetBansError::DatabaseError(error),
+        }
+    }
+}
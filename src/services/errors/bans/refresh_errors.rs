@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub enum RefreshBansError {
+    UserNotFound,
+    SteamUserNotFound,
+    SteamApiError(String),
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for RefreshBansError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => RefreshBansError::UserNotFound,
+            _ => RefreshBansError::DatabaseError(error),
+        }
+    }
+}
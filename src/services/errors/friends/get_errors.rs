@@ -0,0 +1,15 @@
+#[derive(Debug)]
+pub enum GetFriendsError {
+    UserNotFound,
+    ProfilePrivate,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for GetFriendsError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => GetFriendsError::UserNotFound,
+            _ => GetFriendsError::DatabaseError(error),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub enum ImportFriendsError {
+    UserNotFound,
+    FriendListPrivate,
+    SteamApiError(String),
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ImportFriendsError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => ImportFriendsError::UserNotFound,
+            _ => ImportFriendsError::DatabaseError(error),
+        }
+    }
+}
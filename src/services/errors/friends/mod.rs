@@ -0,0 +1,2 @@
+pub mod get_errors;
+pub mod import_errors;
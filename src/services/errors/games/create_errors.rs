@@ -1,9 +1,15 @@
+use crate::models::steam_app::steam_app::SteamApp;
+
 #[derive(Debug)]
 pub enum CreateGameError {
     #[allow(dead_code)]
     GameAlreadyExists,
     SteamApiError(String),
     SteamGameNotFound,
+    MissingAppIdOrName,
+    // No app has this exact name, the closest ones are attached
+    AppNameNotFound(Vec<SteamApp>),
+    AmbiguousAppName(Vec<SteamApp>),
     DatabaseError(sqlx::Error),
 }
 
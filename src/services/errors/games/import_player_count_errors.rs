@@ -0,0 +1,15 @@
+#[derive(Debug)]
+pub enum ImportPlayerCountError {
+    GameNotFound,
+    SteamApiError(String),
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ImportPlayerCountError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => ImportPlayerCountError::GameNotFound,
+            _ => ImportPlayerCountError::DatabaseError(error),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+#[derive(Debug)]
+pub enum ImportPricesError {
+    GameNotFound,
+    SteamApiError(String),
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ImportPricesError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => ImportPricesError::GameNotFound,
+            _ => ImportPricesError::DatabaseError(error),
+        }
+    }
+}
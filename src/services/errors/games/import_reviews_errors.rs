@@ -0,0 +1,15 @@
+#[derive(Debug)]
+pub enum ImportReviewsError {
+    GameNotFound,
+    SteamApiError(String),
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ImportReviewsError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => ImportReviewsError::GameNotFound,
+            _ => ImportReviewsError::DatabaseError(error),
+        }
+    }
+}
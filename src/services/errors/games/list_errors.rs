@@ -0,0 +1,11 @@
+#[derive(Debug)]
+pub enum ListGamesError {
+    InvalidCursor,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ListGamesError {
+    fn from(error: sqlx::Error) -> Self {
+        ListGamesError::DatabaseError(error)
+    }
+}
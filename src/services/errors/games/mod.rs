@@ -1,2 +1,8 @@
 pub mod create_errors;
 pub mod get_errors;
+pub mod import_player_count_errors;
+pub mod import_prices_errors;
+pub mod import_reviews_errors;
+pub mod list_errors;
+pub mod player_counts_errors;
+pub mod resync_errors;
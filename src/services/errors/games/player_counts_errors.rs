@@ -0,0 +1,15 @@
+#[derive(Debug)]
+pub enum GetPlayerCountsError {
+    GameNotFound,
+    InvalidRange,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for GetPlayerCountsError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => GetPlayerCountsError::GameNotFound,
+            _ => GetPlayerCountsError::DatabaseError(error),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub enum ResyncGameError {
+    GameNotFound,
+    SteamGameNotFound,
+    SteamApiError(String),
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ResyncGameError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => ResyncGameError::GameNotFound,
+            _ => ResyncGameError::DatabaseError(error),
+        }
+    }
+}
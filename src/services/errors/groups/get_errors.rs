@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub enum GetGroupsError {
+    UserNotFound,
+    ProfilePrivate,
+    GroupNotFound,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for GetGroupsError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => GetGroupsError::UserNotFound,
+            _ => GetGroupsError::DatabaseError(error),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub enum ImportGroupsError {
+    UserNotFound,
+    ProfilePrivate,
+    SteamApiError(String),
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ImportGroupsError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => ImportGroupsError::UserNotFound,
+            _ => ImportGroupsError::DatabaseError(error),
+        }
+    }
+}
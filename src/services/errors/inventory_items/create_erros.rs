@@ -8,6 +8,7 @@ pub enum CreateInventoryItemError {
     SteamApiError(String),
     #[allow(dead_code)]
     InventoryNotFound,
+    ProfilePrivate,
 }
 
 impl From<sqlx::Error> for CreateInventoryItemError {
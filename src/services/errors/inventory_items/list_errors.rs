@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub enum ListInventoryItemsError {
+    UserNotFound,
+    ProfilePrivate,
+    InvalidCursor,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ListInventoryItemsError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => ListInventoryItemsError::UserNotFound,
+            _ => ListInventoryItemsError::DatabaseError(error),
+        }
+    }
+}
@@ -1 +1,2 @@
 pub mod create_erros;
+pub mod list_errors;
@@ -5,3 +5,27 @@ pub mod games;
 pub mod inventory;
 
 pub mod inventory_items;
+
+pub mod user_games;
+
+pub mod friends;
+
+pub mod achievements;
+
+pub mod bans;
+
+pub mod progression;
+
+pub mod groups;
+
+pub mod search;
+
+pub mod profile;
+
+pub mod privacy;
+
+pub mod watchlists;
+
+pub mod steam_apps;
+
+pub mod news;
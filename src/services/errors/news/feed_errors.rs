@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub enum GetNewsFeedError {
+    UserNotFound,
+    ProfilePrivate,
+    InvalidCursor,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for GetNewsFeedError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => GetNewsFeedError::UserNotFound,
+            _ => GetNewsFeedError::DatabaseError(error),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+#[derive(Debug)]
+pub enum ImportNewsError {
+    GameNotFound,
+    SteamApiError(String),
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ImportNewsError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => ImportNewsError::GameNotFound,
+            _ => ImportNewsError::DatabaseError(error),
+        }
+    }
+}
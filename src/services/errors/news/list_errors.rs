@@ -0,0 +1,15 @@
+#[derive(Debug)]
+pub enum ListGameNewsError {
+    GameNotFound,
+    InvalidCursor,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ListGameNewsError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => ListGameNewsError::GameNotFound,
+            _ => ListGameNewsError::DatabaseError(error),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod feed_errors;
+pub mod import_errors;
+pub mod list_errors;
@@ -0,0 +1,14 @@
+#[derive(Debug)]
+pub enum EraseUserError {
+    UserNotFound,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for EraseUserError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => EraseUserError::UserNotFound,
+            _ => EraseUserError::DatabaseError(error),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+#[derive(Debug)]
+pub enum ExportUserError {
+    UserNotFound,
+    ArchiveError(String),
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ExportUserError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => ExportUserError::UserNotFound,
+            _ => ExportUserError::DatabaseError(error),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod erase_errors;
+pub mod export_errors;
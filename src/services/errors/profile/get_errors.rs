@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub enum GetProfileError {
+    UserNotFound,
+    ProfilePrivate,
+    InvalidSection(String),
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for GetProfileError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => GetProfileError::UserNotFound,
+            _ => GetProfileError::DatabaseError(error),
+        }
+    }
+}
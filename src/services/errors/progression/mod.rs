@@ -0,0 +1 @@
+pub mod refresh_errors;
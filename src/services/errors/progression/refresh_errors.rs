@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub enum RefreshProgressionError {
+    UserNotFound,
+    ProfilePrivate,
+    SteamApiError(String),
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for RefreshProgressionError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => RefreshProgressionError::UserNotFound,
+            _ => RefreshProgressionError::DatabaseError(error),
+        }
+    }
+}
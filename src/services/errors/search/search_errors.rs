@@ -0,0 +1,11 @@
+#[derive(Debug)]
+pub enum SearchError {
+    QueryTooShort,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for SearchError {
+    fn from(error: sqlx::Error) -> Self {
+        SearchError::DatabaseError(error)
+    }
+}
@@ -0,0 +1,11 @@
+#[derive(Debug)]
+pub enum ImportSteamAppsError {
+    SteamApiError(String),
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ImportSteamAppsError {
+    fn from(error: sqlx::Error) -> Self {
+        ImportSteamAppsError::DatabaseError(error)
+    }
+}
@@ -0,0 +1,15 @@
+#[derive(Debug)]
+pub enum GetUserGamesError {
+    UserNotFound,
+    ProfilePrivate,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for GetUserGamesError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => GetUserGamesError::UserNotFound,
+            _ => GetUserGamesError::DatabaseError(error),
+        }
+    }
+}
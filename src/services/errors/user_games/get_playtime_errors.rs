@@ -0,0 +1,16 @@
+#[derive(Debug)]
+pub enum GetPlaytimeError {
+    UserNotFound,
+    ProfilePrivate,
+    InvalidRange,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for GetPlaytimeError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => GetPlaytimeError::UserNotFound,
+            _ => GetPlaytimeError::DatabaseError(error),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+#[derive(Debug)]
+pub enum ImportUserGamesError {
+    UserNotFound,
+    SteamApiError(String),
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for ImportUserGamesError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => ImportUserGamesError::UserNotFound,
+            _ => ImportUserGamesError::DatabaseError(error),
+        }
+    }
+}
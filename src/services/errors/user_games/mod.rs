@@ -0,0 +1,3 @@
+pub mod get_errors;
+pub mod get_playtime_errors;
+pub mod import_errors;
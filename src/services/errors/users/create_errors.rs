@@ -1,6 +1,7 @@
 #[derive(Debug)]
 pub enum CreateUserError {
     UserAlreadyExists,
+    UserDeleted,
     SteamApiError(String),
     SteamUserNotFound,
     DatabaseError(sqlx::Error),
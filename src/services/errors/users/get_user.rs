@@ -1,6 +1,7 @@
 #[derive(Debug)]
 pub enum GetUserError {
     UserNotFound,
+    ProfilePrivate,
     DatabaseError(sqlx::Error),
 }
 
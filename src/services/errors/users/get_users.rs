@@ -0,0 +1,11 @@
+#[derive(Debug)]
+pub enum GetUsersError {
+    InvalidCursor,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for GetUsersError {
+    fn from(error: sqlx::Error) -> Self {
+        GetUsersError::DatabaseError(error)
+    }
+}
@@ -1,4 +1,6 @@
 pub mod create_errors;
 pub mod delete_errors;
 pub mod get_user;
+pub mod get_users;
+pub mod restore_errors;
 pub mod update_errors;
@@ -0,0 +1,11 @@
+#[derive(Debug)]
+pub enum RestoreUserError {
+    UserNotFound,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for RestoreUserError {
+    fn from(error: sqlx::Error) -> Self {
+        RestoreUserError::DatabaseError(error)
+    }
+}
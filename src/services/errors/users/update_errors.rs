@@ -25,6 +25,11 @@ impl From<crate::services::errors::games::create_errors::CreateGameError> for Up
             crate::services::errors::games::create_errors::CreateGameError::SteamGameNotFound => {
                 UpdateUserError::GameCreationError("Steam game not found".to_string())
             }
+            crate::services::errors::games::create_errors::CreateGameError::MissingAppIdOrName
+            | crate::services::errors::games::create_errors::CreateGameError::AppNameNotFound(_)
+            | crate::services::errors::games::create_errors::CreateGameError::AmbiguousAppName(_) => {
+                UpdateUserError::GameCreationError("Game name could not be resolved".to_string())
+            }
             crate::services::errors::games::create_errors::CreateGameError::DatabaseError(err) => {
                 UpdateUserError::DatabaseError(err)
             }
@@ -0,0 +1,18 @@
+#[derive(Debug)]
+pub enum AddWatchlistItemError {
+    OwnerNotFound,
+    GameNotFound,
+    UntrackedRegion,
+    InvalidThreshold,
+    SteamApiError(String),
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for AddWatchlistItemError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => AddWatchlistItemError::OwnerNotFound,
+            _ => AddWatchlistItemError::DatabaseError(error),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+#[derive(Debug)]
+pub enum GetWatchlistError {
+    OwnerNotFound,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for GetWatchlistError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => GetWatchlistError::OwnerNotFound,
+            _ => GetWatchlistError::DatabaseError(error),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod add_errors;
+pub mod get_errors;
+pub mod remove_errors;
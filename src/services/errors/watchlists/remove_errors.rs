@@ -0,0 +1,15 @@
+#[derive(Debug)]
+pub enum RemoveWatchlistItemError {
+    OwnerNotFound,
+    WatchlistItemNotFound,
+    DatabaseError(sqlx::Error),
+}
+
+impl From<sqlx::Error> for RemoveWatchlistItemError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => RemoveWatchlistItemError::OwnerNotFound,
+            _ => RemoveWatchlistItemError::DatabaseError(error),
+        }
+    }
+}
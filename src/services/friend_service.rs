@@ -0,0 +1,186 @@
+use chrono::DateTime;
+use reqwest::StatusCode;
+use sqlx::PgPool;
+
+use crate::{
+    models::{
+        ResponseStatus,
+        friend::{
+            constants::MAX_FRIEND_REGISTRATIONS_PER_IMPORT,
+            dto::{
+                GetFriendsResponse, GetMutualFriendsResponse, ImportFriendsResponse,
+                UpsertFriendSchema,
+            },
+        },
+        user::viewer::Viewer,
+    },
+    repositories::{friend_repository::FriendRepository, user_repository::UserRepository},
+    services::{
+        errors::friends::{get_errors::GetFriendsError, import_errors::ImportFriendsError},
+        user_service::UserService,
+    },
+    steam::steam_friend_list_response::SteamFriendListResponse,
+};
+
+pub struct FriendService;
+
+impl FriendService {
+    async fn fetch_friend_list(
+        steam_id: &str,
+    ) -> Result<SteamFriendListResponse, ImportFriendsError> {
+        dotenv::dotenv().ok();
+        let key = std::env::var("STEAM_KEY").map_err(|_| {
+            ImportFriendsError::SteamApiError("Steam API Key not found".to_string())
+        })?;
+
+        let steam_api = format!(
+            "http://api.steampowered.com/ISteamUser/GetFriendList/v0001/?key={}&steamid={}&relationship=friend&format=json",
+            key, steam_id
+        );
+
+        let response = reqwest::get(&steam_api)
+            .await
+            .map_err(|e| ImportFriendsError::SteamApiError(format!("Failed to fetch: {:?}", e)))?;
+
+        // Steam answers 401 when the friend list is not public
+        if response.status() == StatusCode::UNAUTHORIZED {
+            return Err(ImportFriendsError::FriendListPrivate);
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| ImportFriendsError::SteamApiError(format!("Failed to parse: {:?}", e)))
+    }
+
+    pub async fn import_friends(
+        pool: &PgPool,
+        steam_id: &str,
+        register_friends: bool,
+    ) -> Result<ImportFriendsResponse, ImportFriendsError> {
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let friends = Self::fetch_friend_list(steam_id).await?.friendslist.friends;
+
+        let mut friend_steam_ids = Vec::with_capacity(friends.len());
+        let mut registered = 0;
+        let mut registration_attempts = 0;
+        let mut pending_registrations = 0;
+
+        for friend in friends {
+            let friend_since = friend
+                .friend_since
+                .filter(|timestamp| *timestamp > 0)
+                .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0));
+
+            FriendRepository::upsert_friend(
+                pool,
+                UpsertFriendSchema {
+                    user_id,
+                    friend_steam_id: friend.steamid.clone(),
+                    relationship: friend.relationship,
+                    friend_since,
+                },
+            )
+            .await?;
+
+            if register_friends
+                && !UserRepository::check_if_user_exits(pool, &friend.steamid).await?
+            {
+                if registration_attempts == MAX_FRIEND_REGISTRATIONS_PER_IMPORT {
+                    pending_registrations += 1;
+                } else {
+                    registration_attempts += 1;
+                    if UserService::create_user(pool, friend.steamid.clone())
+                        .await
+                        .is_ok()
+                    {
+                        registered += 1;
+                    }
+                }
+            }
+
+            friend_steam_ids.push(friend.steamid);
+        }
+
+        FriendRepository::delete_removed_friends(pool, user_id, &friend_steam_ids).await?;
+
+        Ok(ImportFriendsResponse {
+            status: ResponseStatus::Success,
+            imported: friend_steam_ids.len(),
+            registered,
+            pending_registrations,
+        })
+    }
+
+    pub async fn get_friends(
+        pool: &PgPool,
+        steam_id: &str,
+        viewer: Viewer,
+    ) -> Result<GetFriendsResponse, GetFriendsError> {
+        let visibility = UserRepository::get_visibility(pool, steam_id).await?;
+        if !viewer.can_view(visibility) {
+            return Err(GetFriendsError::ProfilePrivate);
+        }
+
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let friends =
+            FriendRepository::get_tracked_friends(pool, user_id, !viewer.is_admin()).await?;
+
+        Ok(GetFriendsResponse {
+            status: ResponseStatus::Success,
+            total: friends.len(),
+            friends,
+        })
+    }
+
+    pub async fn get_mutual_friends(
+        pool: &PgPool,
+        steam_id: &str,
+        other_steam_id: &str,
+        viewer: Viewer,
+    ) -> Result<GetMutualFriendsResponse, GetFriendsError> {
+        // Both friend lists are read, so both profiles have to be visible
+        for steam_id in [steam_id, other_steam_id] {
+            let visibility = UserRepository::get_visibility(pool, steam_id).await?;
+            if !viewer.can_view(visibility) {
+                return Err(GetFriendsError::ProfilePrivate);
+            }
+        }
+
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+        let other_user_id = UserRepository::get_user_id_by_steam_id(pool, other_steam_id).await?;
+
+        let friends = FriendRepository::get_mutual_friends(pool, user_id, other_user_id).await?;
+
+        Ok(GetMutualFriendsResponse {
+            status: ResponseStatus::Success,
+            total: friends.len(),
+            friends,
+        })
+    }
+
+    pub async fn get_friends_playing(
+        pool: &PgPool,
+        steam_id: &str,
+        appid: &str,
+        viewer: Viewer,
+    ) -> Result<GetFriendsResponse, GetFriendsError> {
+        let visibility = UserRepository::get_visibility(pool, steam_id).await?;
+        if !viewer.can_view(visibility) {
+            return Err(GetFriendsError::ProfilePrivate);
+        }
+
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let friends =
+            FriendRepository::get_friends_playing(pool, user_id, appid, !viewer.is_admin()).await?;
+
+        Ok(GetFriendsResponse {
+            status: ResponseStatus::Success,
+            total: friends.len(),
+            friends,
+        })
+    }
+}
@@ -0,0 +1,174 @@
+use actix_web::rt;
+use sqlx::PgPool;
+
+use crate::{
+    models::{
+        ResponseStatus,
+        game::{
+            constants::{PRICE_REGION_DELAY, TRACKED_PRICE_REGIONS},
+            dto::{
+                GetCurrentPricesResponse, GetLowestPricesResponse, GetPriceHistoryResponse,
+                ImportGamePricesResponse, UpsertGamePriceSchema,
+            },
+        },
+    },
+    repositories::{game_price_repository::GamePriceRepository, game_repository::GameRepository},
+    services::{
+        errors::games::{get_errors::GetGameError, import_prices_errors::ImportPricesError},
+        watchlist_service::WatchlistService,
+    },
+    steam::steam_api_response::{PriceOverview, SteamGamePrice, SteamGamePriceResponse},
+};
+
+pub struct GamePriceService;
+
+impl GamePriceService {
+    // `None` when the game is free or not sold in the region
+    async fn fetch_region_price(
+        appid: &str,
+        country_code: &str,
+    ) -> Result<Option<PriceOverview>, ImportPricesError> {
+        let steam_api = format!(
+            "https://store.steampowered.com/api/appdetails?appids={}&cc={}&filters=price_overview",
+            appid, country_code
+        );
+
+        let response: SteamGamePriceResponse = reqwest::get(&steam_api)
+            .await
+            .map_err(|e| ImportPricesError::SteamApiError(format!("Failed to fetch: {:?}", e)))?
+            .json()
+            .await
+            .map_err(|e| ImportPricesError::SteamApiError(format!("Failed to parse: {:?}", e)))?;
+
+        let price = response
+            .games
+            .into_iter()
+            .find(|(id, _)| id == appid)
+            .filter(|(_, wrapper)| wrapper.success)
+            .and_then(|(_, wrapper)| wrapper.data);
+
+        Ok(match price {
+            Some(SteamGamePrice::Priced { price_overview }) => Some(price_overview),
+            _ => None,
+        })
+    }
+
+    pub async fn import_game_prices(
+        pool: &PgPool,
+        appid: &str,
+    ) -> Result<ImportGamePricesResponse, ImportPricesError> {
+        let game = GameRepository::get_game_by_appid(pool, appid).await?;
+        let mut regions_imported = 0;
+        let mut regions_failed = 0;
+        let mut last_error = None;
+        let mut game_price_updated = false;
+
+        for (index, country_code) in TRACKED_PRICE_REGIONS.iter().enumerate() {
+            // Every region is a separate appdetails call, which is rate limited
+            if index > 0 {
+                rt::time::sleep(PRICE_REGION_DELAY).await;
+            }
+
+            // A failed region is skipped, the others are still imported
+            let price = match Self::fetch_region_price(appid, country_code).await {
+                Ok(Some(price)) => price,
+                Ok(None) => continue,
+                Err(e) => {
+                    regions_failed += 1;
+                    last_error = Some(e);
+                    continue;
+                }
+            };
+            let (Some(currency), Some(final_price)) = (price.currency, price.final_price) else {
+                continue;
+            };
+
+            GamePriceRepository::upsert_game_price(
+                pool,
+                UpsertGamePriceSchema {
+                    game_id: game.id,
+                    country_code: country_code.to_string(),
+                    currency: currency.clone(),
+                    initial_price: price.initial.unwrap_or(final_price),
+                    final_price,
+                    discount_percent: price.discount_percent.unwrap_or(0),
+                },
+            )
+            .await?;
+
+            // The first region the game is sold in feeds the price shown on the game
+            if !game_price_updated {
+                GameRepository::update_game_price(pool, &game.id, final_price, &currency).await?;
+                game_price_updated = true;
+            }
+
+            regions_imported += 1;
+        }
+
+        // Games whose regions all failed stay due for the next price job run
+        if regions_failed == TRACKED_PRICE_REGIONS.len()
+            && let Some(e) = last_error
+        {
+            return Err(e);
+        }
+        GameRepository::mark_prices_imported(pool, &game.id).await?;
+
+        let alerts_triggered = WatchlistService::evaluate_game(pool, game.id).await?;
+
+        Ok(ImportGamePricesResponse {
+            status: ResponseStatus::Success,
+            appid: game.appid,
+            regions_imported,
+            regions_failed,
+            alerts_triggered,
+        })
+    }
+
+    pub async fn get_current_prices(
+        pool: &PgPool,
+        appid: &str,
+        country_code: Option<&str>,
+    ) -> Result<GetCurrentPricesResponse, GetGameError> {
+        let game = GameRepository::get_game_by_appid(pool, appid).await?;
+        let prices = GamePriceRepository::get_current_prices(pool, game.id, country_code).await?;
+
+        Ok(GetCurrentPricesResponse {
+            status: ResponseStatus::Success,
+            appid: game.appid,
+            prices,
+        })
+    }
+
+    pub async fn get_lowest_prices(
+        pool: &PgPool,
+        appid: &str,
+        country_code: Option<&str>,
+    ) -> Result<GetLowestPricesResponse, GetGameError> {
+        let game = GameRepository::get_game_by_appid(pool, appid).await?;
+        let prices = GamePriceRepository::get_lowest_prices(pool, game.id, country_code).await?;
+
+        Ok(GetLowestPricesResponse {
+            status: ResponseStatus::Success,
+            appid: game.appid,
+            prices,
+        })
+    }
+
+    pub async fn get_price_history(
+        pool: &PgPool,
+        appid: &str,
+        country_code: Option<&str>,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+    ) -> Result<GetPriceHistoryResponse, GetGameError> {
+        let game = GameRepository::get_game_by_appid(pool, appid).await?;
+        let history =
+            GamePriceRepository::get_price_history(pool, game.id, country_code, from, to).await?;
+
+        Ok(GetPriceHistoryResponse {
+            status: ResponseStatus::Success,
+            appid: game.appid,
+            history,
+        })
+    }
+}
@@ -0,0 +1,88 @@
+use sqlx::PgPool;
+
+use crate::{
+    models::{
+        ResponseStatus,
+        game::dto::{GetReviewHistoryResponse, ImportGameReviewsResponse, UpsertGameReviewSchema},
+    },
+    repositories::{game_repository::GameRepository, game_review_repository::GameReviewRepository},
+    services::errors::games::{
+        get_errors::GetGameError, import_reviews_errors::ImportReviewsError,
+    },
+    steam::steam_review_response::{SteamReviewResponse, SteamReviewSummary},
+};
+
+pub struct GameReviewService;
+
+impl GameReviewService {
+    // Only the summary is needed, so no reviews are requested
+    async fn fetch_review_summary(appid: &str) -> Result<SteamReviewSummary, ImportReviewsError> {
+        let steam_api = format!(
+            "https://store.steampowered.com/appreviews/{}?json=1&language=all&purchase_type=all&num_per_page=0",
+            appid
+        );
+
+        let response: SteamReviewResponse = reqwest::get(&steam_api)
+            .await
+            .map_err(|e| ImportReviewsError::SteamApiError(format!("Failed to fetch: {:?}", e)))?
+            .json()
+            .await
+            .map_err(|e| ImportReviewsError::SteamApiError(format!("Failed to parse: {:?}", e)))?;
+
+        response
+            .query_summary
+            .filter(|_| response.success == 1)
+            .ok_or_else(|| ImportReviewsError::SteamApiError("No review summary".to_string()))
+    }
+
+    // Records today's summary and keeps the latest one on the game for the catalog
+    pub async fn import_game_reviews(
+        pool: &PgPool,
+        appid: &str,
+    ) -> Result<ImportGameReviewsResponse, ImportReviewsError> {
+        let game = GameRepository::get_game_by_appid(pool, appid).await?;
+        let summary = Self::fetch_review_summary(appid).await?;
+
+        // The snapshot and the summary kept on the game are written together
+        let mut tx = pool.begin().await?;
+        let review = GameReviewRepository::upsert_game_review(
+            &mut *tx,
+            UpsertGameReviewSchema {
+                game_id: game.id,
+                review_score: summary.review_score,
+                review_score_desc: summary.review_score_desc,
+                total_positive: summary.total_positive,
+                total_negative: summary.total_negative,
+                total_reviews: summary.total_reviews,
+            },
+        )
+        .await?;
+        GameRepository::update_game_reviews(&mut *tx, &game.id, &review).await?;
+        tx.commit().await?;
+
+        Ok(ImportGameReviewsResponse {
+            status: ResponseStatus::Success,
+            appid: game.appid,
+            review_score: review.review_score,
+            review_score_desc: review.review_score_desc,
+            total_positive: review.total_positive,
+            total_negative: review.total_negative,
+        })
+    }
+
+    pub async fn get_review_history(
+        pool: &PgPool,
+        appid: &str,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+    ) -> Result<GetReviewHistoryResponse, GetGameError> {
+        let game = GameRepository::get_game_by_appid(pool, appid).await?;
+        let history = GameReviewRepository::get_review_history(pool, game.id, from, to).await?;
+
+        Ok(GetReviewHistoryResponse {
+            status: ResponseStatus::Success,
+            appid: game.appid,
+            history,
+        })
+    }
+}
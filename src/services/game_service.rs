@@ -1,15 +1,223 @@
-use sqlx::PgPool;
+use actix_web::rt;
+use chrono::NaiveDate;
+use serde_json::{Value, json};
+use sqlx::{PgConnection, PgPool};
 
 use crate::{
-    models::game::{dto::CreateGameSchema, game::{GameCreationResponse, GetGameResponse}},
+    models::{
+        ResponseStatus,
+        game::{
+            dto::{
+                CreateGameSchema, GetGameChangesResponse, GetGameDlcResponse, GetGamesResponse,
+                ResyncGameResponse,
+            },
+            game::{GameCreationResponse, GameModel, GetGameResponse},
+            game_movie::GameMovie,
+            pagination::game_filters::{GameFilters, GameSortField},
+            sync_status::GameSyncStatus,
+        },
+        pagination::{Cursor, CursorPage, PageRequest, SortOrder},
+        user::dto::get_users::PaginationMeta,
+    },
     repositories::game_repository::GameRepository,
-    services::errors::games::{create_errors::CreateGameError, get_errors::GetGameError},
-    steam::steam_api_response::SteamGameResponse,
+    services::{
+        achievement_service::AchievementService,
+        errors::games::{
+            create_errors::CreateGameError, get_errors::GetGameError, list_errors::ListGamesError,
+            resync_errors::ResyncGameError,
+        },
+        game_price_service::GamePriceService,
+        game_review_service::GameReviewService,
+        news_service::NewsService,
+    },
+    steam::steam_api_response::{SteamGame, SteamGameResponse},
 };
 
 pub struct GameService;
 
 impl GameService {
+    // The store formats release dates by region, e.g. `21 Aug, 2012` or `Aug 21, 2012`.
+    // Placeholders such as `Coming soon` or `Q3 2025` are stored as unknown.
+    fn parse_release_date(date: &str) -> Option<NaiveDate> {
+        ["%d %b, %Y", "%b %d, %Y", "%d %B, %Y", "%B %d, %Y"]
+            .iter()
+            .find_map(|format| NaiveDate::parse_from_str(date.trim(), format).ok())
+    }
+
+    // `supported_languages` is HTML such as `English<strong>*</strong>, French<br><strong>*</strong>
+    // languages with full audio support`. The footnote is dropped and the audio markers stripped.
+    fn parse_supported_languages(languages: &str) -> Vec<String> {
+        let languages = languages.split("<br>").next().unwrap_or_default();
+
+        let mut text = String::with_capacity(languages.len());
+        let mut in_tag = false;
+        for c in languages.chars() {
+            match c {
+                '<' => in_tag = true,
+                '>' => in_tag = false,
+                _ if !in_tag => text.push(c),
+                _ => {}
+            }
+        }
+
+        text.split(',')
+            .map(|language| language.trim().trim_end_matches('*').trim().to_string())
+            .filter(|language| !language.is_empty())
+            .collect()
+    }
+
+    // Genres, categories, developers and publishers go to shared lookup tables, movies are per game
+    async fn import_game_metadata(
+        conn: &mut PgConnection,
+        game_id: &uuid::Uuid,
+        game: &SteamGame,
+    ) -> Result<(), sqlx::Error> {
+        for genre in game.genres.iter().flatten() {
+            let genre_id =
+                GameRepository::upsert_genre(&mut *conn, &genre.id, &genre.description).await?;
+            GameRepository::bind_game_genre(&mut *conn, game_id, &genre_id).await?;
+        }
+        for category in game.categories.iter().flatten() {
+            let category_id =
+                GameRepository::upsert_category(&mut *conn, category.id, &category.description)
+                    .await?;
+            GameRepository::bind_game_category(&mut *conn, game_id, &category_id).await?;
+        }
+        for developer in &game.developers {
+            let developer_id = GameRepository::upsert_developer(&mut *conn, developer).await?;
+            GameRepository::bind_game_developer(&mut *conn, game_id, &developer_id).await?;
+        }
+        for publisher in &game.publishers {
+            let publisher_id = GameRepository::upsert_publisher(&mut *conn, publisher).await?;
+            GameRepository::bind_game_publisher(&mut *conn, game_id, &publisher_id).await?;
+        }
+        for movie in game.movies.iter().flatten() {
+            let movie = GameMovie {
+                steam_movie_id: movie.id,
+                name: movie.name.clone(),
+                thumbnail: movie.thumbnail.clone(),
+                webm_480: movie.webm.as_ref().and_then(|webm| webm.res_480.clone()),
+                webm_max: movie.webm.as_ref().and_then(|webm| webm.max.clone()),
+                mp4_480: movie.mp4.as_ref().and_then(|mp4| mp4.res_480.clone()),
+                mp4_max: movie.mp4.as_ref().and_then(|mp4| mp4.max.clone()),
+                highlight: movie.highlight,
+            };
+            GameRepository::upsert_game_movie(&mut *conn, game_id, &movie).await?;
+        }
+
+        // DLC and demos are linked from both sides, whichever of them is stored first
+        let appid = game.steam_appid.to_string();
+        let children = game.dlc.iter().flatten().map(|dlc| dlc.to_string()).chain(
+            game.demos
+                .iter()
+                .flatten()
+                .map(|demo| demo.appid.to_string()),
+        );
+        for child_appid in children {
+            GameRepository::bind_game_relation(&mut *conn, &appid, &child_appid).await?;
+        }
+        if let Some(fullgame) = &game.fullgame {
+            GameRepository::bind_game_relation(&mut *conn, &fullgame.appid.to_string(), &appid)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    fn build_game_schema(appid: &str, game: &SteamGame) -> CreateGameSchema {
+        let screenshots = game
+            .screenshots
+            .as_ref()
+            .map(|s| {
+                s.iter()
+                    .map(|screenshot| screenshot.path_full.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+        CreateGameSchema {
+            appid: appid.to_string(),
+            app_type: game.game_type.to_lowercase(),
+            name: game.name.clone(),
+            short_description: game.short_description.clone(),
+            header_image: game.header_image.clone(),
+            screenshots,
+            is_free: game.is_free,
+            // Free games have no price_overview, they are stored as costing nothing
+            price_final: match &game.price_overview {
+                Some(price) => price.final_price,
+                None if game.is_free => Some(0),
+                None => None,
+            },
+            price_currency: game
+                .price_overview
+                .as_ref()
+                .and_then(|price| price.currency.clone()),
+            release_date: Self::parse_release_date(&game.release_date.date),
+            platform_windows: game.platforms.windows,
+            platform_mac: game.platforms.mac,
+            platform_linux: game.platforms.linux,
+            coming_soon: game.release_date.coming_soon,
+            required_age: game.required_age,
+            metacritic_score: game.metacritic.as_ref().map(|metacritic| metacritic.score),
+            metacritic_url: game
+                .metacritic
+                .as_ref()
+                .map(|metacritic| metacritic.url.clone()),
+            website: game.website.clone().filter(|website| !website.is_empty()),
+            supported_languages: Self::parse_supported_languages(&game.supported_languages),
+            background: game.background.clone(),
+        }
+    }
+
+    // appdetails answers `success: false` for unknown, delisted and region locked appids
+    async fn fetch_store_game(appid: &str) -> Result<SteamGame, CreateGameError> {
+        let mut steam_game_data = Self::fetch_steam_game_data(appid).await?;
+        let game_wrapper = steam_game_data
+            .games
+            .remove(appid)
+            .ok_or(CreateGameError::SteamGameNotFound)?;
+        if !game_wrapper.success {
+            return Err(CreateGameError::SteamGameNotFound);
+        }
+
+        game_wrapper.data.ok_or(CreateGameError::SteamGameNotFound)
+    }
+
+    // Store fields compared between syncs, in the order changes are recorded
+    async fn store_snapshot(
+        conn: &mut PgConnection,
+        game: &GameModel,
+    ) -> Result<Vec<(&'static str, Value)>, sqlx::Error> {
+        let taxonomy = GameRepository::get_game_taxonomy(&mut *conn, &game.id).await?;
+        let movies = GameRepository::get_game_movies(&mut *conn, &game.id).await?;
+        let movie_ids: Vec<i32> = movies.iter().map(|movie| movie.steam_movie_id).collect();
+
+        Ok(vec![
+            ("app_type", json!(game.app_type)),
+            ("name", json!(game.name)),
+            ("short_description", json!(game.short_description)),
+            ("header_image", json!(game.header_image)),
+            ("screenshots", json!(game.screenshots)),
+            ("is_free", json!(game.is_free)),
+            ("price_final", json!(game.price_final)),
+            ("price_currency", json!(game.price_currency)),
+            ("release_date", json!(game.release_date)),
+            ("coming_soon", json!(game.coming_soon)),
+            ("required_age", json!(game.required_age)),
+            ("metacritic_score", json!(game.metacritic_score)),
+            ("metacritic_url", json!(game.metacritic_url)),
+            ("website", json!(game.website)),
+            ("supported_languages", json!(game.supported_languages)),
+            ("background", json!(game.background)),
+            ("platforms", json!(game.platforms())),
+            ("genres", json!(taxonomy.genres)),
+            ("categories", json!(taxonomy.categories)),
+            ("developers", json!(taxonomy.developers)),
+            ("publishers", json!(taxonomy.publishers)),
+            ("movies", json!(movie_ids)),
+        ])
+    }
+
     pub async fn fetch_steam_game_data(appid: &str) -> Result<SteamGameResponse, CreateGameError> {
         let steam_api = format!(
             "https://store.steampowered.com/api/appdetails?appids={}",
@@ -26,48 +234,63 @@ impl GameService {
             .map_err(|e| CreateGameError::SteamApiError(format!("Failed to parse: {:?}", e)))
     }
 
+    // The imports are rate limited (prices pause between regions), so they run in the
+    // background instead of delaying the response
+    fn spawn_store_imports(pool: &PgPool, appid: &str, import_schema: bool) {
+        let pool = pool.clone();
+        let appid = appid.to_string();
+        rt::spawn(async move {
+            if import_schema
+                && let Err(e) = AchievementService::import_game_schema(&pool, &appid).await
+            {
+                eprintln!("Achievement schema import failed for {}: {:?}", appid, e);
+            }
+            if let Err(e) = GamePriceService::import_game_prices(&pool, &appid).await {
+                eprintln!("Price import failed for {}: {:?}", appid, e);
+            }
+            if let Err(e) = NewsService::import_game_news(&pool, &appid).await {
+                eprintln!("News import failed for {}: {:?}", appid, e);
+            }
+            if let Err(e) = GameReviewService::import_game_reviews(&pool, &appid).await {
+                eprintln!("Review import failed for {}: {:?}", appid, e);
+            }
+        });
+    }
+
     pub async fn create_game(
         pool: &PgPool,
         appid: String,
     ) -> Result<GameCreationResponse, CreateGameError> {
         let existing_game = GameRepository::check_if_game_exists(pool, &appid).await?;
-
-        let game_id = if existing_game {
-            // If game exists, get its ID instead of creating
-            let existing = GameRepository::get_game_by_appid(pool, &appid).await?;
-            existing.id
+        let stored = if existing_game {
+            Some(GameRepository::get_game_by_appid(pool, &appid).await?)
         } else {
-            // Create new game
-            let steam_game_data = Self::fetch_steam_game_data(&appid).await?;
-            let game_wrapper = steam_game_data
-                .games
-                .get(&appid)
-                .ok_or(CreateGameError::SteamGameNotFound)?;
-            if !game_wrapper.success {
-                return Err(CreateGameError::SteamGameNotFound);
+            None
+        };
+
+        let game_id = match stored {
+            // Games already synced from appdetails are returned as they are
+            Some(stored) if stored.last_synced_at.is_some() => stored.id,
+            stored => {
+                let game = Self::fetch_store_game(&appid).await?;
+                let create_schema = Self::build_game_schema(&appid, &game);
+                let mut tx = pool.begin().await?;
+                // Library stubs only carry the appid and name, they are filled in from appdetails
+                let db_game = match stored {
+                    Some(stub) => {
+                        GameRepository::update_game(&mut *tx, &stub.id, create_schema).await?
+                    }
+                    None => GameRepository::create_game(&mut *tx, create_schema).await?,
+                };
+                Self::import_game_metadata(&mut tx, &db_game.id, &game).await?;
+                tx.commit().await?;
+
+                // appdetails only carries a few highlighted achievements, so the full schema is imported
+                let import_schema = game.achievements.as_ref().is_some_and(|a| a.total > 0);
+                Self::spawn_store_imports(pool, &appid, import_schema);
+
+                db_game.id
             }
-            let game = game_wrapper
-                .data
-                .as_ref()
-                .ok_or(CreateGameError::SteamGameNotFound)?;
-            let screenshots = game
-                .screenshots
-                .as_ref()
-                .map(|s| {
-                    s.iter()
-                        .map(|screenshot| screenshot.path_full.clone())
-                        .collect()
-                })
-                .unwrap_or_default();
-            let create_schema = CreateGameSchema {
-                appid: appid.clone(),
-                name: game.name.clone(),
-                short_description: game.short_description.clone(),
-                header_image: game.header_image.clone(),
-                screenshots,
-            };
-            let db_game = GameRepository::create_game(pool, create_schema).await?;
-            db_game.id
         };
 
         // Fetch the game details to return
@@ -88,13 +311,187 @@ impl GameService {
         appid: &str,
     ) -> Result<GetGameResponse, GetGameError> {
         let game = GameRepository::get_game_by_appid(pool, appid).await?;
+        let taxonomy = GameRepository::get_game_taxonomy(pool, &game.id).await?;
+        let movies = GameRepository::get_game_movies(pool, &game.id).await?;
+        let parent_appid = GameRepository::get_parent_appid(pool, &game.appid).await?;
 
         Ok(GetGameResponse {
+            platforms: game.platforms(),
             appid: game.appid,
+            app_type: game.app_type,
+            parent_appid,
             name: game.name,
             short_description: game.short_description,
             header_image: game.header_image,
             screenshots: game.screenshots,
+            is_free: game.is_free,
+            price_final: game.price_final,
+            price_currency: game.price_currency,
+            release_date: game.release_date,
+            coming_soon: game.coming_soon,
+            required_age: game.required_age,
+            metacritic_score: game.metacritic_score,
+            metacritic_url: game.metacritic_url,
+            review_score: game.review_score,
+            review_score_desc: game.review_score_desc,
+            review_total_positive: game.review_total_positive,
+            review_total_negative: game.review_total_negative,
+            website: game.website,
+            supported_languages: game.supported_languages,
+            background: game.background,
+            taxonomy,
+            movies,
+            sync_status: game.sync_status,
+            last_synced_at: game.last_synced_at,
+        })
+    }
+
+    // Refreshes a stored game from the store and records the fields that changed
+    pub async fn resync_game(
+        pool: &PgPool,
+        appid: &str,
+    ) -> Result<ResyncGameResponse, ResyncGameError> {
+        let stored = GameRepository::get_game_by_appid(pool, appid).await?;
+
+        let game = match Self::fetch_store_game(appid).await {
+            Ok(game) => game,
+            Err(e) => {
+                let (status, message, error) = match e {
+                    CreateGameError::SteamApiError(msg) => (
+                        GameSyncStatus::Failed,
+                        msg.clone(),
+                        ResyncGameError::SteamApiError(msg),
+                    ),
+                    CreateGameError::DatabaseError(err) => {
+                        return Err(ResyncGameError::DatabaseError(err));
+                    }
+                    _ => (
+                        GameSyncStatus::NotFound,
+                        "Game not found on Steam".to_string(),
+                        ResyncGameError::SteamGameNotFound,
+                    ),
+                };
+                GameRepository::mark_sync_failed(pool, &stored.id, status, &message).await?;
+                return Err(error);
+            }
+        };
+
+        // The game, its metadata and the recorded changes are replaced together
+        let mut tx = pool.begin().await?;
+        let before = Self::store_snapshot(&mut tx, &stored).await?;
+        let updated = GameRepository::update_game(
+            &mut *tx,
+            &stored.id,
+            Self::build_game_schema(appid, &game),
+        )
+        .await?;
+        GameRepository::clear_game_metadata(&mut *tx, &stored.id).await?;
+        Self::import_game_metadata(&mut tx, &stored.id, &game).await?;
+        let after = Self::store_snapshot(&mut tx, &updated).await?;
+
+        // Games first stored from a library were never synced, filling them in is not a change
+        let mut changes = Vec::new();
+        if stored.last_synced_at.is_some() {
+            for ((field, old_value), (_, new_value)) in before.into_iter().zip(after) {
+                if old_value != new_value {
+                    let change = GameRepository::insert_game_change(
+                        &mut *tx, &stored.id, field, old_value, new_value,
+                    )
+                    .await?;
+                    changes.push(change);
+                }
+            }
+        }
+        tx.commit().await?;
+
+        // Review summaries keep their own history instead of being recorded as changes
+        if let Err(e) = GameReviewService::import_game_reviews(pool, appid).await {
+            eprintln!("Review import failed for {}: {:?}", appid, e);
+        }
+
+        Ok(ResyncGameResponse {
+            status: ResponseStatus::Success,
+            appid: updated.appid,
+            sync_status: updated.sync_status,
+            last_synced_at: updated.last_synced_at,
+            changes,
+        })
+    }
+
+    pub async fn get_game_dlc(
+        pool: &PgPool,
+        appid: &str,
+        app_type: Option<&str>,
+    ) -> Result<GetGameDlcResponse, GetGameError> {
+        let game = GameRepository::get_game_by_appid(pool, appid).await?;
+        let dlc = GameRepository::get_game_children(pool, &game.appid, app_type).await?;
+
+        Ok(GetGameDlcResponse {
+            status: ResponseStatus::Success,
+            appid: game.appid,
+            total: dlc.len(),
+            dlc,
+        })
+    }
+
+    pub async fn get_game_changes(
+        pool: &PgPool,
+        appid: &str,
+        limit: i64,
+    ) -> Result<GetGameChangesResponse, GetGameError> {
+        let game = GameRepository::get_game_by_appid(pool, appid).await?;
+        let changes = GameRepository::get_game_changes(pool, &game.id, limit).await?;
+
+        Ok(GetGameChangesResponse {
+            status: ResponseStatus::Success,
+            appid: game.appid,
+            changes,
+        })
+    }
+
+    pub async fn get_games(
+        pool: &PgPool,
+        filters: &GameFilters,
+        sort: GameSortField,
+        order: SortOrder,
+        page_request: &PageRequest,
+    ) -> Result<GetGamesResponse, ListGamesError> {
+        let limit = page_request.limit;
+        // A cursor is only valid for the sort it was issued for
+        let cursor_key = format!("games:{}:{}", sort.as_sort_expression(), order.as_sql());
+
+        let cursor = match &page_request.cursor {
+            Some(encoded) => {
+                Some(Cursor::decode(encoded, &cursor_key).ok_or(ListGamesError::InvalidCursor)?)
+            }
+            None => None,
+        };
+
+        let rows =
+            GameRepository::get_games_paginated(pool, filters, sort, order, cursor.as_ref(), limit)
+                .await?;
+
+        let total = if page_request.include_total {
+            Some(GameRepository::count_games(pool, filters).await?)
+        } else {
+            None
+        };
+
+        let games_page = CursorPage::from_rows(rows, limit, &cursor_key, cursor.as_ref(), false);
+        // The displayed price is the one the price filters ran on
+        debug_assert!(
+            games_page
+                .items
+                .iter()
+                .all(|game| filters.matches_price(game.price_final))
+        );
+
+        Ok(GetGamesResponse {
+            status: ResponseStatus::Success,
+            pagination: PaginationMeta::new(games_page.items.len(), total, None, limit),
+            games: games_page.items,
+            next_cursor: games_page.next_cursor,
+            prev_cursor: games_page.prev_cursor,
         })
     }
 }
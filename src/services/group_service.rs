@@ -0,0 +1,108 @@
+use sqlx::PgPool;
+
+use crate::{
+    models::{
+        ResponseStatus,
+        group::dto::{GetGroupMembersResponse, GetUserGroupsResponse, ImportGroupsResponse},
+        user::viewer::Viewer,
+    },
+    repositories::{group_repository::GroupRepository, user_repository::UserRepository},
+    services::errors::groups::{get_errors::GetGroupsError, import_errors::ImportGroupsError},
+    steam::steam_user_group_list_response::SteamUserGroupListResponse,
+};
+
+pub struct GroupService;
+
+impl GroupService {
+    async fn fetch_user_group_list(
+        steam_id: &str,
+    ) -> Result<SteamUserGroupListResponse, ImportGroupsError> {
+        dotenv::dotenv().ok();
+        let key = std::env::var("STEAM_KEY")
+            .map_err(|_| ImportGroupsError::SteamApiError("Steam API Key not found".to_string()))?;
+
+        let steam_api = format!(
+            "http://api.steampowered.com/ISteamUser/GetUserGroupList/v1/?key={}&steamid={}&format=json",
+            key, steam_id
+        );
+
+        let response = reqwest::get(&steam_api)
+            .await
+            .map_err(|e| ImportGroupsError::SteamApiError(format!("Failed to fetch: {:?}", e)))?;
+
+        // Error statuses still carry a JSON body with `success: false`
+        response
+            .json()
+            .await
+            .map_err(|e| ImportGroupsError::SteamApiError(format!("Failed to parse: {:?}", e)))
+    }
+
+    pub async fn import_groups(
+        pool: &PgPool,
+        steam_id: &str,
+    ) -> Result<ImportGroupsResponse, ImportGroupsError> {
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let group_list = Self::fetch_user_group_list(steam_id).await?.response;
+        if !group_list.success {
+            return Err(ImportGroupsError::ProfilePrivate);
+        }
+
+        let mut group_ids = Vec::with_capacity(group_list.groups.len());
+
+        for group in group_list.groups {
+            let db_group = GroupRepository::upsert_group(pool, &group.gid).await?;
+            GroupRepository::bind_user_to_group(pool, user_id, db_group.id).await?;
+            group_ids.push(db_group.id);
+        }
+
+        GroupRepository::delete_left_groups(pool, user_id, &group_ids).await?;
+
+        Ok(ImportGroupsResponse {
+            status: ResponseStatus::Success,
+            imported: group_ids.len(),
+        })
+    }
+
+    pub async fn get_user_groups(
+        pool: &PgPool,
+        steam_id: &str,
+        viewer: Viewer,
+    ) -> Result<GetUserGroupsResponse, GetGroupsError> {
+        let visibility = UserRepository::get_visibility(pool, steam_id).await?;
+        if !viewer.can_view(visibility) {
+            return Err(GetGroupsError::ProfilePrivate);
+        }
+
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let groups = GroupRepository::get_user_groups(pool, user_id).await?;
+
+        Ok(GetUserGroupsResponse {
+            status: ResponseStatus::Success,
+            total: groups.len(),
+            groups,
+        })
+    }
+
+    pub async fn get_group_members(
+        pool: &PgPool,
+        gid: &str,
+        viewer: Viewer,
+    ) -> Result<GetGroupMembersResponse, GetGroupsError> {
+        let existing_group = GroupRepository::check_if_group_exists(pool, gid).await?;
+        if !existing_group {
+            return Err(GetGroupsError::GroupNotFound);
+        }
+
+        // Group lists of private profiles are hidden on Steam as well
+        let members = GroupRepository::get_group_members(pool, gid, !viewer.is_admin()).await?;
+
+        Ok(GetGroupMembersResponse {
+            status: ResponseStatus::Success,
+            gid: gid.to_string(),
+            total: members.len(),
+            members,
+        })
+    }
+}
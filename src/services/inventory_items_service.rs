@@ -1,10 +1,18 @@
+use reqwest::StatusCode;
 use sqlx::PgPool;
 
 use crate::{
-    models::inventory_items::dto::{CreateItemSchema, ItemCreationResponse},
+    models::{
+        ResponseStatus,
+        inventory_items::dto::{CreateItemSchema, GetInventoryItemsResponse, ItemCreationResponse},
+        pagination::{Cursor, CursorPage, PageRequest},
+        user::{constants::PUBLIC_VISIBILITY, dto::get_users::PaginationMeta, viewer::Viewer},
+    },
     repositories::inventory_items::InventoryItemsRepository,
     repositories::inventory_repository::InventoryRepository,
+    repositories::user_repository::UserRepository,
     services::errors::inventory_items::create_erros::CreateInventoryItemError,
+    services::errors::inventory_items::list_errors::ListInventoryItemsError,
     steam::steam_inventory_response::SteamInventoryResponse,
 };
 
@@ -26,6 +34,11 @@ impl InventoryItemService {
             CreateInventoryItemError::SteamApiError(format!("Failed to fetch inventory: {:?}", e))
         })?;
 
+        // Steam answers private inventories with 403 and an empty body
+        if response.status() == StatusCode::FORBIDDEN {
+            return Err(CreateInventoryItemError::ProfilePrivate);
+        }
+
         if !response.status().is_success() {
             return Err(CreateInventoryItemError::SteamApiError(format!(
                 "Steam API returned status: {}",
@@ -59,6 +72,11 @@ impl InventoryItemService {
             .await?
             .ok_or(CreateInventoryItemError::InventoryNotFound)?;
 
+        let visibility = UserRepository::get_visibility(pool, steam_id).await?;
+        if visibility != PUBLIC_VISIBILITY {
+            return Err(CreateInventoryItemError::ProfilePrivate);
+        }
+
         let inventory_data = Self::fetch_steam_inventory(steam_id, app_id).await?;
 
         let mut saved_items = Vec::new();
@@ -106,4 +124,55 @@ impl InventoryItemService {
 
         Ok(saved_items)
     }
+
+    pub async fn get_user_items(
+        pool: &PgPool,
+        steam_id: &str,
+        app_id: Option<&str>,
+        page_request: &PageRequest,
+        viewer: Viewer,
+    ) -> Result<GetInventoryItemsResponse, ListInventoryItemsError> {
+        let visibility = UserRepository::get_visibility(pool, steam_id).await?;
+        if !viewer.can_view(visibility) {
+            return Err(ListInventoryItemsError::ProfilePrivate);
+        }
+
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let limit = page_request.limit;
+        let cursor_key = "inventory_items:name";
+
+        let cursor = match &page_request.cursor {
+            Some(encoded) => Some(
+                Cursor::decode(encoded, cursor_key)
+                    .ok_or(ListInventoryItemsError::InvalidCursor)?,
+            ),
+            None => None,
+        };
+
+        let rows = InventoryItemsRepository::get_user_items_paginated(
+            pool,
+            user_id,
+            app_id,
+            cursor.as_ref(),
+            limit,
+        )
+        .await?;
+
+        let total = if page_request.include_total {
+            Some(InventoryItemsRepository::count_user_items(pool, user_id, app_id).await?)
+        } else {
+            None
+        };
+
+        let items_page = CursorPage::from_rows(rows, limit, cursor_key, cursor.as_ref(), false);
+
+        Ok(GetInventoryItemsResponse {
+            status: ResponseStatus::Success,
+            pagination: PaginationMeta::new(items_page.items.len(), total, None, limit),
+            items: items_page.items,
+            next_cursor: items_page.next_cursor,
+            prev_cursor: items_page.prev_cursor,
+        })
+    }
 }
@@ -6,3 +6,33 @@ pub mod game_service;
 pub mod inventory_service;
 
 pub mod inventory_items_service;
+
+pub mod user_games_service;
+
+pub mod friend_service;
+
+pub mod achievement_service;
+
+pub mod ban_service;
+
+pub mod progression_service;
+
+pub mod group_service;
+
+pub mod search_service;
+
+pub mod profile_service;
+
+pub mod privacy_service;
+
+pub mod game_price_service;
+
+pub mod watchlist_service;
+
+pub mod steam_app_service;
+
+pub mod news_service;
+
+pub mod player_count_service;
+
+pub mod game_review_service;
@@ -0,0 +1,163 @@
+use chrono::DateTime;
+use sqlx::PgPool;
+
+use crate::{
+    models::{
+        ResponseStatus,
+        news::{
+            constants::NEWS_ITEMS_PER_IMPORT,
+            dto::{GetNewsResponse, ImportGameNewsResponse, UpsertNewsItemSchema},
+            news_scope::NewsScope,
+        },
+        pagination::{Cursor, CursorPage, PageRequest},
+        user::{dto::get_users::PaginationMeta, viewer::Viewer},
+    },
+    repositories::{
+        game_repository::GameRepository, news_repository::NewsRepository,
+        user_repository::UserRepository,
+    },
+    services::errors::news::{
+        feed_errors::GetNewsFeedError, import_errors::ImportNewsError,
+        list_errors::ListGameNewsError,
+    },
+    steam::steam_news_response::SteamNewsResponse,
+};
+
+// Both listings are newest first, so one cursor key fits them
+const NEWS_CURSOR_KEY: &str = "news:published_at";
+
+pub struct NewsService;
+
+impl NewsService {
+    // `maxlength=0` returns the full contents of every article
+    async fn fetch_news(appid: &str) -> Result<SteamNewsResponse, ImportNewsError> {
+        let steam_api = format!(
+            "https://api.steampowered.com/ISteamNews/GetNewsForApp/v0002/?appid={}&count={}&maxlength=0&format=json",
+            appid, NEWS_ITEMS_PER_IMPORT
+        );
+
+        let response = reqwest::get(&steam_api)
+            .await
+            .map_err(|e| ImportNewsError::SteamApiError(format!("Failed to fetch: {:?}", e)))?;
+
+        response
+            .json()
+            .await
+            .map_err(|e| ImportNewsError::SteamApiError(format!("Failed to parse: {:?}", e)))
+    }
+
+    pub async fn import_game_news(
+        pool: &PgPool,
+        appid: &str,
+    ) -> Result<ImportGameNewsResponse, ImportNewsError> {
+        let game = GameRepository::get_game_by_appid(pool, appid).await?;
+        let news = Self::fetch_news(appid).await?;
+
+        let fetched = news.appnews.newsitems.len();
+        let mut new_items = 0;
+        for item in news.appnews.newsitems {
+            let Some(published_at) = DateTime::from_timestamp(item.date, 0) else {
+                continue;
+            };
+
+            let inserted = NewsRepository::upsert_news_item(
+                pool,
+                UpsertNewsItemSchema {
+                    game_id: game.id,
+                    gid: item.gid,
+                    title: item.title,
+                    url: item.url,
+                    author: item.author.filter(|author| !author.is_empty()),
+                    contents: item.contents,
+                    feed_label: item.feedlabel,
+                    feed_name: item.feedname,
+                    is_external_url: item.is_external_url,
+                    published_at,
+                },
+            )
+            .await?;
+            if inserted == Some(true) {
+                new_items += 1;
+            }
+        }
+
+        Ok(ImportGameNewsResponse {
+            status: ResponseStatus::Success,
+            appid: game.appid,
+            fetched,
+            new_items,
+        })
+    }
+
+    async fn get_news_page(
+        pool: &PgPool,
+        scope: NewsScope,
+        feed: Option<&str>,
+        page_request: &PageRequest,
+        cursor: Option<Cursor>,
+    ) -> Result<GetNewsResponse, sqlx::Error> {
+        let limit = page_request.limit;
+
+        let rows =
+            NewsRepository::get_news_paginated(pool, scope, feed, cursor.as_ref(), limit).await?;
+
+        let total = if page_request.include_total {
+            Some(NewsRepository::count_news(pool, scope, feed).await?)
+        } else {
+            None
+        };
+
+        let news_page = CursorPage::from_rows(rows, limit, NEWS_CURSOR_KEY, cursor.as_ref(), false);
+
+        Ok(GetNewsResponse {
+            status: ResponseStatus::Success,
+            pagination: PaginationMeta::new(news_page.items.len(), total, None, limit),
+            news: news_page.items,
+            next_cursor: news_page.next_cursor,
+            prev_cursor: news_page.prev_cursor,
+        })
+    }
+
+    pub async fn get_game_news(
+        pool: &PgPool,
+        appid: &str,
+        feed: Option<&str>,
+        page_request: &PageRequest,
+    ) -> Result<GetNewsResponse, ListGameNewsError> {
+        let cursor = match &page_request.cursor {
+            Some(encoded) => Some(
+                Cursor::decode(encoded, NEWS_CURSOR_KEY).ok_or(ListGameNewsError::InvalidCursor)?,
+            ),
+            None => None,
+        };
+
+        let game = GameRepository::get_game_by_appid(pool, appid).await?;
+
+        Ok(Self::get_news_page(pool, NewsScope::Game(game.id), feed, page_request, cursor).await?)
+    }
+
+    // News of every game in the user's library, follows the profile's visibility
+    pub async fn get_user_news(
+        pool: &PgPool,
+        steam_id: &str,
+        feed: Option<&str>,
+        page_request: &PageRequest,
+        viewer: Viewer,
+    ) -> Result<GetNewsResponse, GetNewsFeedError> {
+        let visibility = UserRepository::get_visibility(pool, steam_id).await?;
+        if !viewer.can_view(visibility) {
+            return Err(GetNewsFeedError::ProfilePrivate);
+        }
+
+        let cursor = match &page_request.cursor {
+            Some(encoded) => Some(
+                Cursor::decode(encoded, NEWS_CURSOR_KEY).ok_or(GetNewsFeedError::InvalidCursor)?,
+            ),
+            None => None,
+        };
+
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        Ok(Self::get_news_page(pool, NewsScope::User(user_id), feed, page_request, cursor).await?)
+    }
+}
@@ -0,0 +1,111 @@
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+
+use crate::{
+    models::{
+        ResponseStatus,
+        game::{
+            constants::PLAYER_COUNT_DEFAULT_DAYS,
+            dto::{
+                GetPlayerCountsResponse, GetTrendingGamesResponse, ImportPlayerCountResponse,
+                PlayerCountBucket,
+            },
+        },
+    },
+    repositories::{
+        game_repository::GameRepository, player_count_repository::PlayerCountRepository,
+    },
+    services::errors::games::{
+        import_player_count_errors::ImportPlayerCountError,
+        player_counts_errors::GetPlayerCountsError,
+    },
+    steam::steam_player_count_response::SteamPlayerCountResponse,
+};
+
+pub struct PlayerCountService;
+
+impl PlayerCountService {
+    async fn fetch_player_count(appid: &str) -> Result<Option<i32>, ImportPlayerCountError> {
+        let steam_api = format!(
+            "https://api.steampowered.com/ISteamUserStats/GetNumberOfCurrentPlayers/v1/?appid={}",
+            appid
+        );
+
+        let response: SteamPlayerCountResponse = reqwest::get(&steam_api)
+            .await
+            .map_err(|e| {
+                ImportPlayerCountError::SteamApiError(format!("Failed to fetch: {:?}", e))
+            })?
+            .json()
+            .await
+            .map_err(|e| {
+                ImportPlayerCountError::SteamApiError(format!("Failed to parse: {:?}", e))
+            })?;
+
+        Ok(response
+            .response
+            .player_count
+            .filter(|_| response.response.result == 1))
+    }
+
+    pub async fn import_player_count(
+        pool: &PgPool,
+        appid: &str,
+    ) -> Result<ImportPlayerCountResponse, ImportPlayerCountError> {
+        let game = GameRepository::get_game_by_appid(pool, appid).await?;
+        let player_count = Self::fetch_player_count(appid).await?;
+
+        if let Some(player_count) = player_count {
+            PlayerCountRepository::insert_player_count(pool, game.id, player_count).await?;
+        }
+
+        Ok(ImportPlayerCountResponse {
+            status: ResponseStatus::Success,
+            appid: game.appid,
+            player_count,
+        })
+    }
+
+    pub async fn get_player_counts(
+        pool: &PgPool,
+        appid: &str,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+        bucket: PlayerCountBucket,
+    ) -> Result<GetPlayerCountsResponse, GetPlayerCountsError> {
+        let to = to.unwrap_or_else(|| Utc::now().date_naive());
+        let from = from.unwrap_or(to - Duration::days(PLAYER_COUNT_DEFAULT_DAYS));
+        if from > to {
+            return Err(GetPlayerCountsError::InvalidRange);
+        }
+
+        let game = GameRepository::get_game_by_appid(pool, appid).await?;
+        let points =
+            PlayerCountRepository::get_player_counts(pool, game.id, bucket, from, to).await?;
+
+        Ok(GetPlayerCountsResponse {
+            status: ResponseStatus::Success,
+            appid: game.appid,
+            bucket: bucket.as_str().to_string(),
+            from,
+            to,
+            points,
+        })
+    }
+
+    pub async fn get_trending_games(
+        pool: &PgPool,
+        hours: i32,
+        min_players: i32,
+        limit: i64,
+    ) -> Result<GetTrendingGamesResponse, sqlx::Error> {
+        let games =
+            PlayerCountRepository::get_trending_games(pool, hours, min_players, limit).await?;
+
+        Ok(GetTrendingGamesResponse {
+            status: ResponseStatus::Success,
+            hours,
+            games,
+        })
+    }
+}
@@ -0,0 +1,110 @@
+use std::io::Write;
+
+use serde_json::{Value, json};
+use sqlx::PgPool;
+use zip::{CompressionMethod, ZipWriter, write::SimpleFileOptions};
+
+use crate::{
+    models::{
+        ResponseStatus,
+        privacy::{dto::EraseUserResponse, privacy_action::PrivacyAction},
+    },
+    repositories::privacy_repository::PrivacyRepository,
+    services::errors::privacy::{erase_errors::EraseUserError, export_errors::ExportUserError},
+};
+
+pub struct PrivacyService;
+
+impl PrivacyService {
+    fn count_exported_rows(export: &Value) -> i32 {
+        let Some(sections) = export.as_object() else {
+            return 0;
+        };
+
+        sections
+            .iter()
+            .filter(|(key, _)| key.as_str() != "exported_at")
+            .map(|(_, section)| match section {
+                Value::Array(rows) => rows.len() as i32,
+                Value::Null => 0,
+                _ => 1,
+            })
+            .sum()
+    }
+
+    pub async fn export_user(
+        pool: &PgPool,
+        steam_id: &str,
+        performed_by: &str,
+    ) -> Result<Value, ExportUserError> {
+        let export = PrivacyRepository::export_user_data(pool, steam_id).await?;
+        let export: Value = serde_json::from_str(&export)
+            .map_err(|e| ExportUserError::ArchiveError(format!("Failed to parse: {:?}", e)))?;
+
+        PrivacyRepository::record_request(
+            pool,
+            steam_id,
+            PrivacyAction::Export,
+            performed_by,
+            Self::count_exported_rows(&export),
+        )
+        .await?;
+
+        Ok(export)
+    }
+
+    // One pretty printed JSON file per section, plus a manifest with the export time
+    pub fn build_export_archive(export: &Value) -> Result<Vec<u8>, ExportUserError> {
+        let sections = export
+            .as_object()
+            .ok_or_else(|| ExportUserError::ArchiveError("Export is not an object".to_string()))?;
+
+        let manifest = json!({
+            "exported_at": sections.get("exported_at"),
+            "sections": sections.keys().filter(|key| key.as_str() != "exported_at").collect::<Vec<_>>(),
+        });
+
+        let mut archive = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        let files = std::iter::once(("manifest", &manifest)).chain(
+            sections
+                .iter()
+                .filter(|(key, _)| key.as_str() != "exported_at")
+                .map(|(key, section)| (key.as_str(), section)),
+        );
+
+        for (name, section) in files {
+            let contents = serde_json::to_vec_pretty(section)
+                .map_err(|e| ExportUserError::ArchiveError(format!("{:?}", e)))?;
+
+            archive
+                .start_file(format!("{}.json", name), options)
+                .map_err(|e| ExportUserError::ArchiveError(format!("{:?}", e)))?;
+            archive
+                .write_all(&contents)
+                .map_err(|e| ExportUserError::ArchiveError(format!("{:?}", e)))?;
+        }
+
+        let archive = archive
+            .finish()
+            .map_err(|e| ExportUserError::ArchiveError(format!("{:?}", e)))?;
+
+        Ok(archive.into_inner())
+    }
+
+    pub async fn erase_user(
+        pool: &PgPool,
+        steam_id: &str,
+        performed_by: &str,
+    ) -> Result<EraseUserResponse, EraseUserError> {
+        let erased = PrivacyRepository::erase_user(pool, steam_id, performed_by).await?;
+
+        Ok(EraseUserResponse {
+            status: ResponseStatus::Success,
+            steam_id: steam_id.to_string(),
+            rows_erased: erased.total(),
+            erased,
+        })
+    }
+}
@@ -0,0 +1,107 @@
+use sqlx::PgPool;
+
+use crate::{
+    models::{
+        ResponseStatus,
+        game::dto::UserGamesSort,
+        profile::dto::{
+            GetUserProfileResponse, InventoryAppSummary, InventorySummary, InventoryTypeCount,
+            ProfileSection,
+        },
+        user::viewer::Viewer,
+    },
+    repositories::{
+        game_repository::GameRepository, profile_repository::ProfileRepository,
+        user_repository::UserRepository,
+    },
+    services::errors::profile::get_errors::GetProfileError,
+};
+
+pub struct ProfileService;
+
+impl ProfileService {
+    fn parse_sections(include: Option<&str>) -> Result<Vec<ProfileSection>, GetProfileError> {
+        let Some(include) = include.filter(|include| !include.trim().is_empty()) else {
+            return Ok(ProfileSection::ALL.to_vec());
+        };
+
+        include
+            .split(',')
+            .map(str::trim)
+            .filter(|section| !section.is_empty())
+            .map(|section| {
+                ProfileSection::parse(section)
+                    .ok_or_else(|| GetProfileError::InvalidSection(section.to_string()))
+            })
+            .collect()
+    }
+
+    fn summarize_inventory(type_counts: Vec<InventoryTypeCount>) -> InventorySummary {
+        let mut apps: Vec<InventoryAppSummary> = Vec::new();
+
+        // Rows come ordered by app_id, so each app is a contiguous run
+        for type_count in type_counts {
+            match apps.last_mut() {
+                Some(app) if app.app_id == type_count.app_id => {
+                    app.total_items += type_count.item_count;
+                    app.item_types.push(type_count);
+                }
+                _ => apps.push(InventoryAppSummary {
+                    app_id: type_count.app_id.clone(),
+                    total_items: type_count.item_count,
+                    item_types: vec![type_count],
+                }),
+            }
+        }
+
+        InventorySummary {
+            total_items: apps.iter().map(|app| app.total_items).sum(),
+            apps,
+        }
+    }
+
+    pub async fn get_user_profile(
+        pool: &PgPool,
+        steam_id: &str,
+        include: Option<&str>,
+        viewer: Viewer,
+    ) -> Result<GetUserProfileResponse, GetProfileError> {
+        let sections = Self::parse_sections(include)?;
+
+        let visibility = UserRepository::get_visibility(pool, steam_id).await?;
+        if !viewer.can_view(visibility) {
+            return Err(GetProfileError::ProfilePrivate);
+        }
+
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let mut response = GetUserProfileResponse {
+            status: ResponseStatus::Success,
+            user: None,
+            games: None,
+            inventory: None,
+            sync: None,
+        };
+
+        if sections.contains(&ProfileSection::User) {
+            response.user = Some(UserRepository::get_user(pool, steam_id).await?);
+        }
+
+        if sections.contains(&ProfileSection::Games) {
+            response.games = Some(
+                GameRepository::get_user_games(pool, user_id, UserGamesSort::default()).await?,
+            );
+        }
+
+        if sections.contains(&ProfileSection::Inventory) {
+            let type_counts = ProfileRepository::get_inventory_type_counts(pool, user_id).await?;
+            response.inventory = Some(Self::summarize_inventory(type_counts));
+        }
+
+        if sections.contains(&ProfileSection::Sync) {
+            response.sync = Some(ProfileRepository::get_sync_times(pool, user_id).await?);
+        }
+
+        Ok(response)
+    }
+}
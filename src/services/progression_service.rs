@@ -0,0 +1,136 @@
+use chrono::DateTime;
+use sqlx::PgPool;
+
+use crate::{
+    models::{
+        ResponseStatus,
+        progression::dto::{
+            GetLeaderboardResponse, GetUserBadgesResponse, LeaderboardSort, ProgressionSummary,
+            UpsertUserBadgeSchema, UpsertUserProgressionSchema,
+        },
+        user::viewer::Viewer,
+    },
+    repositories::{
+        progression_repository::ProgressionRepository, user_repository::UserRepository,
+    },
+    services::errors::{
+        progression::refresh_errors::RefreshProgressionError, users::get_user::GetUserError,
+    },
+    steam::steam_progression_response::{SteamBadgesResponse, SteamLevelResponse},
+};
+
+pub struct ProgressionService;
+
+impl ProgressionService {
+    async fn fetch_player_service<T: serde::de::DeserializeOwned>(
+        method: &str,
+        steam_id: &str,
+    ) -> Result<T, RefreshProgressionError> {
+        dotenv::dotenv().ok();
+        let key = std::env::var("STEAM_KEY").map_err(|_| {
+            RefreshProgressionError::SteamApiError("Steam API Key not found".to_string())
+        })?;
+
+        let steam_api = format!(
+            "http://api.steampowered.com/IPlayerService/{}/v1/?key={}&steamid={}&format=json",
+            method, key, steam_id
+        );
+
+        let response = reqwest::get(&steam_api).await.map_err(|e| {
+            RefreshProgressionError::SteamApiError(format!("Failed to fetch: {:?}", e))
+        })?;
+
+        response.json().await.map_err(|e| {
+            RefreshProgressionError::SteamApiError(format!("Failed to parse: {:?}", e))
+        })
+    }
+
+    pub async fn refresh_progression(
+        pool: &PgPool,
+        steam_id: &str,
+    ) -> Result<ProgressionSummary, RefreshProgressionError> {
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let level = Self::fetch_player_service::<SteamLevelResponse>("GetSteamLevel", steam_id)
+            .await?
+            .response;
+        let badges = Self::fetch_player_service::<SteamBadgesResponse>("GetBadges", steam_id)
+            .await?
+            .response;
+
+        // Private profiles return an empty response for both calls
+        let steam_level = level
+            .player_level
+            .or(badges.player_level)
+            .ok_or(RefreshProgressionError::ProfilePrivate)?;
+
+        let upsert_schema = UpsertUserProgressionSchema {
+            user_id,
+            steam_level,
+            player_xp: badges.player_xp.unwrap_or(0),
+            xp_needed_to_level_up: badges.player_xp_needed_to_level_up.unwrap_or(0),
+            xp_needed_current_level: badges.player_xp_needed_current_level.unwrap_or(0),
+        };
+        ProgressionRepository::upsert_progression(pool, upsert_schema).await?;
+
+        for badge in badges.badges {
+            let badge_schema = UpsertUserBadgeSchema {
+                user_id,
+                badge_id: badge.badgeid,
+                appid: badge.appid.unwrap_or(0),
+                level: badge.level,
+                xp: badge.xp,
+                scarcity: badge.scarcity,
+                // Badges without a completion date report 0
+                completed_at: Some(badge.completion_time)
+                    .filter(|timestamp| *timestamp > 0)
+                    .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0)),
+            };
+            ProgressionRepository::upsert_badge(pool, badge_schema).await?;
+        }
+
+        ProgressionRepository::get_progression_summary_by_steam_id(pool, steam_id)
+            .await?
+            .ok_or(RefreshProgressionError::UserNotFound)
+    }
+
+    pub async fn get_user_badges(
+        pool: &PgPool,
+        steam_id: &str,
+        viewer: Viewer,
+    ) -> Result<GetUserBadgesResponse, GetUserError> {
+        let existing_user = UserRepository::check_if_user_exits(pool, steam_id).await?;
+        if !existing_user {
+            return Err(GetUserError::UserNotFound);
+        }
+
+        let visibility = UserRepository::get_visibility(pool, steam_id).await?;
+        if !viewer.can_view(visibility) {
+            return Err(GetUserError::ProfilePrivate);
+        }
+
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+        let badges = ProgressionRepository::get_user_badges(pool, user_id).await?;
+
+        Ok(GetUserBadgesResponse {
+            status: ResponseStatus::Success,
+            total: badges.len(),
+            badges,
+        })
+    }
+
+    pub async fn get_leaderboard(
+        pool: &PgPool,
+        sort: LeaderboardSort,
+        limit: i64,
+        viewer: Viewer,
+    ) -> Result<GetLeaderboardResponse, sqlx::Error> {
+        let leaderboard =
+            ProgressionRepository::get_leaderboard(pool, sort, limit, !viewer.is_admin()).await?;
+
+        Ok(GetLeaderboardResponse {
+            status: ResponseStatus::Success,
+            leaderboard,
+        })
+    }
+}
@@ -0,0 +1,47 @@
+use sqlx::PgPool;
+
+use crate::{
+    models::{
+        ResponseStatus,
+        search::{
+            constants::MIN_SEARCH_QUERY_LENGTH,
+            dto::{SearchResponse, SearchResultType},
+        },
+        user::viewer::Viewer,
+    },
+    repositories::search_repository::SearchRepository,
+    services::errors::search::search_errors::SearchError,
+};
+
+pub struct SearchService;
+
+impl SearchService {
+    pub async fn search(
+        pool: &PgPool,
+        query: &str,
+        result_type: Option<SearchResultType>,
+        limit: i64,
+        viewer: Viewer,
+    ) -> Result<SearchResponse, SearchError> {
+        let query = query.trim();
+        if query.chars().count() < MIN_SEARCH_QUERY_LENGTH {
+            return Err(SearchError::QueryTooShort);
+        }
+
+        let results = SearchRepository::search(
+            pool,
+            query,
+            result_type.map(|result_type| result_type.as_str()),
+            limit,
+            !viewer.is_admin(),
+        )
+        .await?;
+
+        Ok(SearchResponse {
+            status: ResponseStatus::Success,
+            query: query.to_string(),
+            total: results.len(),
+            results,
+        })
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+
+use sqlx::PgPool;
+
+use crate::{
+    models::{
+        ResponseStatus,
+        game::dto::CreateGameRequest,
+        search::constants::MIN_SEARCH_QUERY_LENGTH,
+        steam_app::{
+            constants::{MAX_NAME_CANDIDATES, STEAM_APPS_IMPORT_CHUNK_SIZE},
+            dto::{GetSteamAppsResponse, ImportSteamAppsResponse},
+        },
+    },
+    repositories::steam_app_repository::SteamAppRepository,
+    services::errors::{
+        games::create_errors::CreateGameError, search::search_errors::SearchError,
+        steam_apps::import_errors::ImportSteamAppsError,
+    },
+    steam::steam_app_list_response::SteamAppListResponse,
+};
+
+pub struct SteamAppService;
+
+impl SteamAppService {
+    async fn fetch_app_list() -> Result<SteamAppListResponse, ImportSteamAppsError> {
+        let steam_api = "https://api.steampowered.com/ISteamApps/GetAppList/v2/";
+
+        let response = reqwest::get(steam_api).await.map_err(|e| {
+            ImportSteamAppsError::SteamApiError(format!("Failed to fetch: {:?}", e))
+        })?;
+
+        response
+            .json()
+            .await
+            .map_err(|e| ImportSteamAppsError::SteamApiError(format!("Failed to parse: {:?}", e)))
+    }
+
+    // GetAppList lists some appids twice and many without a name, those are skipped
+    pub async fn import_steam_apps(
+        pool: &PgPool,
+    ) -> Result<ImportSteamAppsResponse, ImportSteamAppsError> {
+        let app_list = Self::fetch_app_list().await?;
+
+        let apps: BTreeMap<String, String> = app_list
+            .applist
+            .apps
+            .into_iter()
+            .map(|app| (app.appid.to_string(), app.name.trim().to_string()))
+            .filter(|(_, name)| !name.is_empty())
+            .collect();
+        let (appids, names): (Vec<String>, Vec<String>) = apps.into_iter().unzip();
+
+        let mut updated = 0;
+        for (appid_chunk, name_chunk) in appids
+            .chunks(STEAM_APPS_IMPORT_CHUNK_SIZE)
+            .zip(names.chunks(STEAM_APPS_IMPORT_CHUNK_SIZE))
+        {
+            updated += SteamAppRepository::upsert_steam_apps(pool, appid_chunk, name_chunk).await?;
+        }
+
+        Ok(ImportSteamAppsResponse {
+            status: ResponseStatus::Success,
+            total_apps: appids.len(),
+            updated,
+        })
+    }
+
+    pub async fn search_steam_apps(
+        pool: &PgPool,
+        query: &str,
+        limit: i64,
+    ) -> Result<GetSteamAppsResponse, SearchError> {
+        let query = query.trim();
+        if query.chars().count() < MIN_SEARCH_QUERY_LENGTH {
+            return Err(SearchError::QueryTooShort);
+        }
+
+        let apps = SteamAppRepository::search_steam_apps(pool, query, limit).await?;
+
+        Ok(GetSteamAppsResponse {
+            status: ResponseStatus::Success,
+            query: query.to_string(),
+            total: apps.len(),
+            apps,
+        })
+    }
+
+    // An appid is used as is, a name must match exactly one app. Otherwise the matching
+    // apps, or the closest ones, are reported back so the caller can pick an appid.
+    pub async fn resolve_appid(
+        pool: &PgPool,
+        request: CreateGameRequest,
+    ) -> Result<String, CreateGameError> {
+        if let Some(appid) = request.appid.filter(|appid| !appid.trim().is_empty()) {
+            return Ok(appid.trim().to_string());
+        }
+        let Some(name) = request.name.filter(|name| !name.trim().is_empty()) else {
+            return Err(CreateGameError::MissingAppIdOrName);
+        };
+
+        let mut matches =
+            SteamAppRepository::get_steam_apps_by_name(pool, name.trim(), MAX_NAME_CANDIDATES)
+                .await?;
+        match matches.len() {
+            1 => Ok(matches.remove(0).appid),
+            0 => {
+                let candidates =
+                    SteamAppRepository::search_steam_apps(pool, name.trim(), MAX_NAME_CANDIDATES)
+                        .await?;
+                Err(CreateGameError::AppNameNotFound(candidates))
+            }
+            _ => Err(CreateGameError::AmbiguousAppName(matches)),
+        }
+    }
+}
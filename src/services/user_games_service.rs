@@ -0,0 +1,262 @@
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    models::{
+        ResponseStatus,
+        game::dto::{
+            CreateGameStubSchema, GetPlaytimeResponse, GetUserGamesResponse,
+            ImportOwnedGamesResponse, UpsertPlaytimeSnapshotSchema, UpsertUserGameSchema,
+            UserGamesSort,
+        },
+        user::viewer::Viewer,
+    },
+    repositories::{game_repository::GameRepository, user_repository::UserRepository},
+    services::errors::user_games::{
+        get_errors::GetUserGamesError, get_playtime_errors::GetPlaytimeError,
+        import_errors::ImportUserGamesError,
+    },
+    steam::{
+        steam_owned_games_response::SteamOwnedGamesResponse,
+        steam_recently_played_response::SteamRecentlyPlayedResponse,
+    },
+};
+
+const DEFAULT_PLAYTIME_WINDOW_DAYS: i64 = 7;
+
+pub struct UserGamesService;
+
+impl UserGamesService {
+    async fn fetch_player_service<T: serde::de::DeserializeOwned>(
+        method: &str,
+        steam_id: &str,
+        extra_params: &str,
+    ) -> Result<T, ImportUserGamesError> {
+        dotenv::dotenv().ok();
+        let key = std::env::var("STEAM_KEY").map_err(|_| {
+            ImportUserGamesError::SteamApiError("Steam API Key not found".to_string())
+        })?;
+
+        let steam_api = format!(
+            "http://api.steampowered.com/IPlayerService/{}/v0001/?key={}&steamid={}{}&format=json",
+            method, key, steam_id, extra_params
+        );
+
+        let response = reqwest::get(&steam_api).await.map_err(|e| {
+            ImportUserGamesError::SteamApiError(format!("Failed to fetch: {:?}", e))
+        })?;
+
+        response
+            .json()
+            .await
+            .map_err(|e| ImportUserGamesError::SteamApiError(format!("Failed to parse: {:?}", e)))
+    }
+
+    async fn save_playtime(
+        pool: &PgPool,
+        body: UpsertUserGameSchema,
+    ) -> Result<(), ImportUserGamesError> {
+        let user_game = GameRepository::upsert_user_game(pool, body).await?;
+
+        GameRepository::upsert_playtime_snapshot(
+            pool,
+            UpsertPlaytimeSnapshotSchema {
+                user_id: user_game.user_id,
+                game_id: user_game.game_id,
+                playtime_forever: user_game.playtime_forever,
+                playtime_2weeks: user_game.playtime_2weeks,
+            },
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn import_owned_games(
+        pool: &PgPool,
+        steam_id: &str,
+    ) -> Result<ImportOwnedGamesResponse, ImportUserGamesError> {
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let owned_games = Self::fetch_player_service::<SteamOwnedGamesResponse>(
+            "GetOwnedGames",
+            steam_id,
+            "&include_appinfo=1&include_played_free_games=1",
+        )
+        .await?
+        .response
+        .games;
+
+        for owned_game in &owned_games {
+            let appid = owned_game.appid.to_string();
+
+            // Only the appinfo is stored here, the full store data is fetched by GameService
+            let game = GameRepository::create_game_stub(
+                pool,
+                CreateGameStubSchema {
+                    name: owned_game.name.clone().unwrap_or_else(|| appid.clone()),
+                    appid,
+                },
+            )
+            .await?;
+
+            let last_played_at = owned_game
+                .rtime_last_played
+                .filter(|timestamp| *timestamp > 0)
+                .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0));
+
+            Self::save_playtime(
+                pool,
+                UpsertUserGameSchema {
+                    user_id,
+                    game_id: game.id,
+                    playtime_forever: owned_game.playtime_forever,
+                    playtime_2weeks: owned_game.playtime_2weeks,
+                    last_played_at,
+                },
+            )
+            .await?;
+        }
+
+        Ok(ImportOwnedGamesResponse {
+            status: ResponseStatus::Success,
+            imported: owned_games.len(),
+        })
+    }
+
+    pub async fn import_recently_played_games(
+        pool: &PgPool,
+        steam_id: &str,
+    ) -> Result<ImportOwnedGamesResponse, ImportUserGamesError> {
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let recently_played = Self::fetch_player_service::<SteamRecentlyPlayedResponse>(
+            "GetRecentlyPlayedGames",
+            steam_id,
+            "",
+        )
+        .await?
+        .response;
+
+        // Private profiles answer without `total_count`, their stored playtime is left alone
+        if recently_played.total_count.is_none() {
+            return Ok(ImportOwnedGamesResponse {
+                status: ResponseStatus::Success,
+                imported: 0,
+            });
+        }
+        let recent_games = recently_played.games;
+
+        let mut played_game_ids: Vec<Uuid> = Vec::with_capacity(recent_games.len());
+
+        for recent_game in &recent_games {
+            let appid = recent_game.appid.to_string();
+
+            let game = GameRepository::create_game_stub(
+                pool,
+                CreateGameStubSchema {
+                    name: recent_game.name.clone().unwrap_or_else(|| appid.clone()),
+                    appid,
+                },
+            )
+            .await?;
+
+            Self::save_playtime(
+                pool,
+                UpsertUserGameSchema {
+                    user_id,
+                    game_id: game.id,
+                    playtime_forever: recent_game.playtime_forever,
+                    playtime_2weeks: recent_game.playtime_2weeks,
+                    last_played_at: None,
+                },
+            )
+            .await?;
+
+            played_game_ids.push(game.id);
+        }
+
+        // Games missing from the response were not played in the last two weeks
+        GameRepository::reset_recent_playtime(pool, user_id, &played_game_ids).await?;
+
+        Ok(ImportOwnedGamesResponse {
+            status: ResponseStatus::Success,
+            imported: recent_games.len(),
+        })
+    }
+
+    pub async fn get_user_games(
+        pool: &PgPool,
+        steam_id: &str,
+        sort: UserGamesSort,
+        viewer: Viewer,
+    ) -> Result<GetUserGamesResponse, GetUserGamesError> {
+        let visibility = UserRepository::get_visibility(pool, steam_id).await?;
+        if !viewer.can_view(visibility) {
+            return Err(GetUserGamesError::ProfilePrivate);
+        }
+
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let games = GameRepository::get_user_games(pool, user_id, sort).await?;
+
+        Ok(GetUserGamesResponse {
+            status: ResponseStatus::Success,
+            total: games.len(),
+            games,
+        })
+    }
+
+    pub async fn get_recent_user_games(
+        pool: &PgPool,
+        steam_id: &str,
+        viewer: Viewer,
+    ) -> Result<GetUserGamesResponse, GetUserGamesError> {
+        let visibility = UserRepository::get_visibility(pool, steam_id).await?;
+        if !viewer.can_view(visibility) {
+            return Err(GetUserGamesError::ProfilePrivate);
+        }
+
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let games = GameRepository::get_recent_user_games(pool, user_id).await?;
+
+        Ok(GetUserGamesResponse {
+            status: ResponseStatus::Success,
+            total: games.len(),
+            games,
+        })
+    }
+
+    pub async fn get_playtime(
+        pool: &PgPool,
+        steam_id: &str,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+        viewer: Viewer,
+    ) -> Result<GetPlaytimeResponse, GetPlaytimeError> {
+        let to = to.unwrap_or_else(|| Utc::now().date_naive());
+        let from = from.unwrap_or(to - Duration::days(DEFAULT_PLAYTIME_WINDOW_DAYS));
+        if from > to {
+            return Err(GetPlaytimeError::InvalidRange);
+        }
+
+        let visibility = UserRepository::get_visibility(pool, steam_id).await?;
+        if !viewer.can_view(visibility) {
+            return Err(GetPlaytimeError::ProfilePrivate);
+        }
+
+        let user_id = UserRepository::get_user_id_by_steam_id(pool, steam_id).await?;
+
+        let games = GameRepository::get_playtime_totals(pool, user_id, from, to).await?;
+
+        Ok(GetPlaytimeResponse {
+            status: ResponseStatus::Success,
+            from,
+            to,
+            total_minutes: games.iter().map(|game| game.minutes_played).sum(),
+            games,
+        })
+    }
+}
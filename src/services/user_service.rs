@@ -1,17 +1,30 @@
 use crate::models::ResponseStatus;
 use crate::models::dto::{
-    CreateUserSchema, GetUserResponse, UpdateUser, UpdateUserResponse, UserCreationResponse,
+    CreateUserSchema, GetUser, GetUserResponse, UpdateUser, UpdateUserResponse,
+    UserCreationResponse,
 };
 use crate::models::game::dto::BindUserToGameSchema;
+use crate::models::pagination::{Cursor, CursorPage, PageRequest, SortOrder};
+use crate::models::user::constants::PUBLIC_VISIBILITY;
 use crate::models::user::dto::get_users::{GetUsersResponse, PaginationMeta};
+use crate::models::user::pagination::user_filters::{UserFilters, UserSortField};
+use crate::models::user::viewer::Viewer;
+use crate::repositories::ban_repository::BanRepository;
 use crate::repositories::game_repository::GameRepository;
+use crate::repositories::progression_repository::ProgressionRepository;
 use crate::repositories::user_repository::UserRepository;
+use crate::services::ban_service::BanService;
 use crate::services::errors::users::create_errors::CreateUserError;
 use crate::services::errors::users::delete_errors::DeleteUserError;
 use crate::services::errors::users::get_user::GetUserError;
+use crate::services::errors::users::get_users::GetUsersError;
+use crate::services::errors::users::restore_errors::RestoreUserError;
 use crate::services::errors::users::update_errors::UpdateUserError;
 use crate::services::game_service::GameService;
+use crate::services::group_service::GroupService;
 use crate::services::inventory_service::InventoryService;
+use crate::services::progression_service::ProgressionService;
+use crate::services::user_games_service::UserGamesService;
 use crate::steam::steam_api_response::SteamResponse;
 use chrono::DateTime;
 use sqlx::PgPool;
@@ -22,33 +35,62 @@ pub struct UserService;
 impl UserService {
     pub async fn get_users(
         pool: &PgPool,
-        username: Option<&str>,
+        filters: &UserFilters,
+        sort: UserSortField,
+        order: SortOrder,
         page: i64,
-        limit: i64,
-    ) -> Result<GetUsersResponse, sqlx::Error> {
-        let offset = (page - 1) * limit;
+        page_request: &PageRequest,
+        viewer: Viewer,
+    ) -> Result<GetUsersResponse, GetUsersError> {
+        let limit = page_request.limit;
+        let cursor_key = format!("users:{}:{}", sort.as_sort_expression(), order.as_sql());
+
+        let cursor = match &page_request.cursor {
+            Some(encoded) => {
+                Some(Cursor::decode(encoded, &cursor_key).ok_or(GetUsersError::InvalidCursor)?)
+            }
+            None => None,
+        };
 
-        let (users, total) =
-            UserRepository::get_users_with_count(pool, username, limit, offset).await?;
+        // A cursor already points at the page, `page` only applies without one
+        let offset = if cursor.is_some() {
+            0
+        } else {
+            (page - 1) * limit
+        };
 
-        let total_pages = if total == 0 {
-            1
+        let rows = UserRepository::get_users_paginated(
+            pool,
+            filters,
+            sort,
+            order,
+            cursor.as_ref(),
+            limit,
+            offset,
+        )
+        .await?;
+
+        let total = if page_request.include_total {
+            Some(UserRepository::count_users(pool, filters).await?)
         } else {
-            (total + limit - 1) / limit
+            None
         };
 
-        let total_in_page = users.len();
+        let mut users_page =
+            CursorPage::from_rows(rows, limit, &cursor_key, cursor.as_ref(), offset > 0);
+        for user in users_page.items.iter_mut() {
+            if !viewer.can_view(user.visibility) {
+                user.current_game = None;
+            }
+        }
+        let current_page = cursor.is_none().then_some(page);
 
         let response = GetUsersResponse {
             status: ResponseStatus::Success,
-            users,
-            pagination: PaginationMeta {
-                total_in_page,
-                total,
-                total_pages,
-                current_page: page,
-                page_size: limit,
-            },
+            pagination: PaginationMeta::new(users_page.items.len(), total, current_page, limit),
+            users: users_page.items,
+            next_cursor: users_page.next_cursor,
+            prev_cursor: users_page.prev_cursor,
         };
 
         Ok(response)
@@ -83,6 +125,11 @@ impl UserService {
             return Err(CreateUserError::UserAlreadyExists);
         }
 
+        let deleted_user = UserRepository::check_if_user_is_deleted(pool, &steam_id).await?;
+        if deleted_user {
+            return Err(CreateUserError::UserDeleted);
+        }
+
         let steam_data = Self::fetch_steam_data(&steam_id).await?;
 
         let players = steam_data.response.players;
@@ -91,8 +138,10 @@ impl UserService {
             .next()
             .ok_or(CreateUserError::SteamUserNotFound)?;
 
-        let timestamp = user.timecreated.unwrap_or(0);
-        let formatted_steam_created_at = DateTime::from_timestamp(timestamp, 0);
+        // Private profiles do not expose timecreated
+        let formatted_steam_created_at = user
+            .timecreated
+            .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0));
 
         let gameid = user.gameid.clone();
 
@@ -124,6 +173,15 @@ impl UserService {
         let cloned_steam_id = user.steamid.clone();
         let _ = InventoryService::create_inventory(pool, cloned_steam_id).await;
 
+        let _ = BanService::refresh_bans(pool, &db_user.steam_id).await;
+
+        // Private profiles hide games, level and groups, those calls would only fail
+        if db_user.visibility == PUBLIC_VISIBILITY {
+            let _ = UserGamesService::import_owned_games(pool, &db_user.steam_id).await;
+            let _ = ProgressionService::refresh_progression(pool, &db_user.steam_id).await;
+            let _ = GroupService::import_groups(pool, &db_user.steam_id).await;
+        }
+
         Ok(UserCreationResponse {
             username: db_user.username,
             pf_url: db_user.pf_url,
@@ -132,8 +190,11 @@ impl UserService {
         })
     }
 
-    pub async fn delete_user(pool: &PgPool, user_id: Uuid) -> Result<u64, DeleteUserError> {
-        let rows = UserRepository::delete_user(pool, user_id).await?;
+    // Accepts either the user's uuid or their Steam id
+    pub async fn delete_user(pool: &PgPool, identifier: &str) -> Result<u64, DeleteUserError> {
+        let user_id = Uuid::parse_str(identifier).ok();
+
+        let rows = UserRepository::soft_delete_user(pool, user_id, identifier).await?;
         if rows == 0 {
             return Err(DeleteUserError::UserNotFound);
         }
@@ -141,17 +202,50 @@ impl UserService {
         Ok(rows)
     }
 
-    pub async fn get_user(pool: &PgPool, steam_id: &str) -> Result<GetUserResponse, GetUserError> {
+    pub async fn restore_user(pool: &PgPool, identifier: &str) -> Result<u64, RestoreUserError> {
+        let user_id = Uuid::parse_str(identifier).ok();
+
+        let rows = UserRepository::restore_user(pool, user_id, identifier).await?;
+        if rows == 0 {
+            return Err(RestoreUserError::UserNotFound);
+        }
+
+        Ok(rows)
+    }
+
+    // Only the basic summary stays readable once a profile is no longer public
+    fn hide_private_details(user: &mut GetUser) {
+        user.country = None;
+        user.current_game = None;
+        user.gameid = None;
+        user.steam_created_at = None;
+    }
+
+    pub async fn get_user(
+        pool: &PgPool,
+        steam_id: &str,
+        viewer: Viewer,
+    ) -> Result<GetUserResponse, GetUserError> {
         let existing_user = UserRepository::check_if_user_exits(pool, steam_id).await?;
         if !existing_user {
             return Err(GetUserError::UserNotFound);
         }
 
-        let user = UserRepository::get_user(pool, steam_id).await?;
+        let mut user = UserRepository::get_user(pool, steam_id).await?;
+        let bans = BanRepository::get_ban_status_by_steam_id(pool, steam_id).await?;
+        let mut progression =
+            ProgressionRepository::get_progression_summary_by_steam_id(pool, steam_id).await?;
+
+        if !viewer.can_view(user.visibility) {
+            Self::hide_private_details(&mut user);
+            progression = None;
+        }
 
         let response = GetUserResponse {
             status: ResponseStatus::Success,
             user,
+            bans,
+            progression,
         };
 
         Ok(response)
@@ -175,7 +269,7 @@ impl UserService {
                 CreateUserError::SteamApiError(msg) => UpdateUserError::SteamApiError(msg),
                 CreateUserError::SteamUserNotFound => UpdateUserError::SteamUserNotFound,
                 CreateUserError::DatabaseError(err) => UpdateUserError::DatabaseError(err),
-                CreateUserError::UserAlreadyExists => {
+                CreateUserError::UserAlreadyExists | CreateUserError::UserDeleted => {
                     UpdateUserError::DatabaseError(sqlx::Error::RowNotFound)
                 }
                 CreateUserError::GameCreationError(msg) => {
@@ -212,6 +306,15 @@ impl UserService {
             GameRepository::bind_user_to_game(pool, bind_schema).await?;
         }
 
+        let _ = BanService::refresh_bans(pool, steam_id).await;
+
+        // Data already stored for a profile that went private is kept, reads hide it
+        if update_body.visibility == Some(PUBLIC_VISIBILITY) {
+            let _ = UserGamesService::import_owned_games(pool, steam_id).await;
+            let _ = ProgressionService::refresh_progression(pool, steam_id).await;
+            let _ = GroupService::import_groups(pool, steam_id).await;
+        }
+
         Ok(UpdateUserResponse {
             message: ResponseStatus::Success,
         })
@@ -0,0 +1,186 @@
+use std::time::Duration;
+
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::{
+    models::{
+        ResponseStatus,
+        game::constants::TRACKED_PRICE_REGIONS,
+        watchlist::{
+            dto::{
+                AddWatchlistItemRequest, GetPriceAlertsResponse, GetWatchlistResponse, PriceAlert,
+                RemoveWatchlistItemResponse, WatchlistItemResponse,
+            },
+            watchlist_owner::WatchlistOwner,
+            webhook_status::WebhookStatus,
+        },
+    },
+    repositories::watchlist_repository::WatchlistRepository,
+    services::{
+        errors::{
+            games::create_errors::CreateGameError,
+            watchlists::{
+                add_errors::AddWatchlistItemError, get_errors::GetWatchlistError,
+                remove_errors::RemoveWatchlistItemError,
+            },
+        },
+        game_service::GameService,
+    },
+};
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct WatchlistService;
+
+impl WatchlistService {
+    // Games that are not stored yet are created from the store first
+    pub async fn add_item(
+        pool: &PgPool,
+        owner: &WatchlistOwner,
+        body: AddWatchlistItemRequest,
+    ) -> Result<WatchlistItemResponse, AddWatchlistItemError> {
+        if body.target_price.is_none() && body.discount_threshold.is_none()
+            || body.target_price.is_some_and(|price| price < 0)
+            || body
+                .discount_threshold
+                .is_some_and(|threshold| !(1..=100).contains(&threshold))
+        {
+            return Err(AddWatchlistItemError::InvalidThreshold);
+        }
+
+        let country_code = body
+            .cc
+            .as_deref()
+            .unwrap_or(TRACKED_PRICE_REGIONS[0])
+            .to_uppercase();
+        if !TRACKED_PRICE_REGIONS.contains(&country_code.as_str()) {
+            return Err(AddWatchlistItemError::UntrackedRegion);
+        }
+
+        let owner_ids = WatchlistRepository::resolve_owner(pool, owner).await?;
+
+        let game = GameService::create_game(pool, body.appid)
+            .await
+            .map_err(|e| match e {
+                CreateGameError::SteamApiError(msg) => AddWatchlistItemError::SteamApiError(msg),
+                CreateGameError::DatabaseError(err) => AddWatchlistItemError::DatabaseError(err),
+                _ => AddWatchlistItemError::GameNotFound,
+            })?;
+
+        let id = WatchlistRepository::upsert_watchlist_item(
+            pool,
+            &owner_ids,
+            game.id,
+            &country_code,
+            body.target_price,
+            body.discount_threshold,
+        )
+        .await?;
+        let item = WatchlistRepository::get_watchlist_item(pool, id).await?;
+
+        Ok(WatchlistItemResponse {
+            status: ResponseStatus::Success,
+            item,
+        })
+    }
+
+    pub async fn get_watchlist(
+        pool: &PgPool,
+        owner: &WatchlistOwner,
+    ) -> Result<GetWatchlistResponse, GetWatchlistError> {
+        let owner_ids = WatchlistRepository::resolve_owner(pool, owner).await?;
+        let items = WatchlistRepository::get_watchlist(pool, &owner_ids).await?;
+
+        Ok(GetWatchlistResponse {
+            status: ResponseStatus::Success,
+            items,
+        })
+    }
+
+    pub async fn remove_item(
+        pool: &PgPool,
+        owner: &WatchlistOwner,
+        appid: &str,
+        country_code: Option<&str>,
+    ) -> Result<RemoveWatchlistItemResponse, RemoveWatchlistItemError> {
+        let owner_ids = WatchlistRepository::resolve_owner(pool, owner).await?;
+        let removed =
+            WatchlistRepository::remove_watchlist_item(pool, &owner_ids, appid, country_code)
+                .await?;
+
+        if removed == 0 {
+            return Err(RemoveWatchlistItemError::WatchlistItemNotFound);
+        }
+
+        Ok(RemoveWatchlistItemResponse {
+            status: ResponseStatus::Success,
+            removed,
+        })
+    }
+
+    pub async fn get_alerts(
+        pool: &PgPool,
+        owner: &WatchlistOwner,
+        limit: i64,
+    ) -> Result<GetPriceAlertsResponse, GetWatchlistError> {
+        let owner_ids = WatchlistRepository::resolve_owner(pool, owner).await?;
+        let alerts = WatchlistRepository::get_price_alerts(pool, &owner_ids, limit).await?;
+
+        Ok(GetPriceAlertsResponse {
+            status: ResponseStatus::Success,
+            alerts,
+        })
+    }
+
+    // Runs after every price import of the game, returns how many alerts fired
+    pub async fn evaluate_game(pool: &PgPool, game_id: uuid::Uuid) -> Result<usize, sqlx::Error> {
+        WatchlistRepository::clear_recovered_watchlists(pool, game_id).await?;
+        let alert_ids = WatchlistRepository::create_price_alerts(pool, game_id).await?;
+        if alert_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let alerts = WatchlistRepository::get_price_alerts_by_ids(pool, &alert_ids).await?;
+        for alert in &alerts {
+            let status = Self::send_webhook(alert).await;
+            WatchlistRepository::set_webhook_status(pool, alert.id, status).await?;
+        }
+
+        Ok(alerts.len())
+    }
+
+    // Posts the alert to PRICE_ALERT_WEBHOOK_URL when it is set. Delivery is attempted
+    // once, the outcome is kept on the alert.
+    async fn send_webhook(alert: &PriceAlert) -> WebhookStatus {
+        dotenv::dotenv().ok();
+        let Ok(url) = std::env::var("PRICE_ALERT_WEBHOOK_URL") else {
+            return WebhookStatus::Skipped;
+        };
+
+        let client = match reqwest::Client::builder().timeout(WEBHOOK_TIMEOUT).build() {
+            Ok(client) => client,
+            Err(e) => {
+                eprintln!("Failed to build webhook client: {:?}", e);
+                return WebhookStatus::Failed;
+            }
+        };
+
+        let payload = json!({
+            "event": "price_alert",
+            "alert": alert,
+        });
+
+        match client.post(&url).json(&payload).send().await {
+            Ok(response) if response.status().is_success() => WebhookStatus::Sent,
+            Ok(response) => {
+                eprintln!("Price alert webhook answered {}", response.status());
+                WebhookStatus::Failed
+            }
+            Err(e) => {
+                eprintln!("Price alert webhook failed: {:?}", e);
+                WebhookStatus::Failed
+            }
+        }
+    }
+}
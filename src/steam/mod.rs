@@ -1,2 +1,13 @@
 pub mod steam_api_response;
 pub mod steam_inventory_response;
+pub mod steam_owned_games_response;
+pub mod steam_recently_played_response;
+pub mod steam_friend_list_response;
+pub mod steam_achievements_response;
+pub mod steam_player_bans_response;
+pub mod steam_progression_response;
+pub mod steam_user_group_list_response;
+pub mod steam_app_list_response;
+pub mod steam_news_response;
+pub mod steam_player_count_response;
+pub mod steam_review_response;
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Deserializer};
+
+// Steam has sent the global percentages both as numbers and as strings
+fn deserialize_string_or_float<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrFloat {
+        String(String),
+        Float(f64),
+    }
+
+    match StringOrFloat::deserialize(deserializer)? {
+        StringOrFloat::String(s) => s.parse::<f64>().map_err(serde::de::Error::custom),
+        StringOrFloat::Float(f) => Ok(f),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamGameSchemaResponse {
+    pub game: SteamGameSchema,
+}
+
+// Games without stats come back as an empty `game` object
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SteamGameSchema {
+    #[serde(rename = "gameName")]
+    pub game_name: Option<String>,
+    #[serde(rename = "availableGameStats")]
+    pub available_game_stats: Option<SteamAvailableGameStats>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamAvailableGameStats {
+    #[serde(default)]
+    pub achievements: Vec<SteamSchemaAchievement>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamSchemaAchievement {
+    pub name: String,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub hidden: i32,
+    pub icon: String,
+    pub icongray: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamPlayerAchievementsResponse {
+    pub playerstats: SteamPlayerStats,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamPlayerStats {
+    pub success: bool,
+    pub error: Option<String>,
+    #[serde(default)]
+    pub achievements: Vec<SteamPlayerAchievement>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamPlayerAchievement {
+    pub apiname: String,
+    pub achieved: i32,
+    pub unlocktime: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamGlobalAchievementPercentagesResponse {
+    pub achievementpercentages: SteamGlobalAchievementPercentages,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamGlobalAchievementPercentages {
+    #[serde(default)]
+    pub achievements: Vec<SteamGlobalAchievementPercentage>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamGlobalAchievementPercentage {
+    pub name: String,
+    #[serde(deserialize_with = "deserialize_string_or_float")]
+    pub percent: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn percent(json: &str) -> Result<f64, serde_json::Error> {
+        serde_json::from_str::<SteamGlobalAchievementPercentage>(json).map(|a| a.percent)
+    }
+
+    #[test]
+    fn percent_accepts_numbers() {
+        assert_eq!(
+            percent(r#"{"name": "WIN", "percent": 12.5}"#).unwrap(),
+            12.5
+        );
+        assert_eq!(percent(r#"{"name": "WIN", "percent": 3}"#).unwrap(), 3.0);
+    }
+
+    #[test]
+    fn percent_accepts_numeric_strings() {
+        assert_eq!(
+            percent(r#"{"name": "WIN", "percent": "12.5"}"#).unwrap(),
+            12.5
+        );
+    }
+
+    #[test]
+    fn percent_rejects_other_values() {
+        assert!(percent(r#"{"name": "WIN", "percent": "n/a"}"#).is_err());
+        assert!(percent(r#"{"name": "WIN", "percent": null}"#).is_err());
+    }
+}
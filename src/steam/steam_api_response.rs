@@ -85,6 +85,28 @@ pub struct SteamGameWrapper {
     pub data: Option<SteamGame>,
 }
 
+// appdetails with `filters=price_overview`, `data` is an empty array for free games
+// and for games that are not sold in the requested region
+#[derive(Debug, Deserialize)]
+pub struct SteamGamePriceResponse {
+    #[serde(flatten)]
+    pub games: std::collections::HashMap<String, SteamGamePriceWrapper>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamGamePriceWrapper {
+    pub success: bool,
+    pub data: Option<SteamGamePrice>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+#[allow(dead_code)]
+pub enum SteamGamePrice {
+    Priced { price_overview: PriceOverview },
+    Empty(Vec<()>),
+}
+
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
 pub struct SteamGame {
@@ -97,6 +119,9 @@ pub struct SteamGame {
     pub is_free: bool,
     pub controller_support: Option<String>,
     pub dlc: Option<Vec<u32>>,
+    // Set on DLC, demos and soundtracks, pointing at their parent game
+    pub fullgame: Option<FullGame>,
+    pub demos: Option<Vec<Demo>>,
     pub detailed_description: String,
     pub about_the_game: String,
     pub short_description: String,
@@ -125,6 +150,22 @@ pub struct SteamGame {
     pub background_raw: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct FullGame {
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub appid: i32,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct Demo {
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub appid: i32,
+    pub description: String,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 #[allow(dead_code)]
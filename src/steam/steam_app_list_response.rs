@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct SteamAppListResponse {
+    pub applist: SteamAppList,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamAppList {
+    pub apps: Vec<SteamAppListEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamAppListEntry {
+    pub appid: u32,
+    pub name: String,
+}
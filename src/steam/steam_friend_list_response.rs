@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct SteamFriendListResponse {
+    pub friendslist: SteamFriendList,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamFriendList {
+    #[serde(default)]
+    pub friends: Vec<SteamFriend>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamFriend {
+    pub steamid: String,
+    pub relationship: String,
+    pub friend_since: Option<i64>,
+}
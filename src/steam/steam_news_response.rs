@@ -0,0 +1,34 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct SteamNewsResponse {
+    pub appnews: SteamAppNews,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SteamAppNews {
+    pub appid: u32,
+    #[serde(default)]
+    pub newsitems: Vec<SteamNewsItem>,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SteamNewsItem {
+    pub gid: String,
+    pub title: String,
+    pub url: String,
+    #[serde(default)]
+    pub is_external_url: bool,
+    pub author: Option<String>,
+    #[serde(default)]
+    pub contents: String,
+    #[serde(default)]
+    pub feedlabel: String,
+    // Unix timestamp
+    pub date: i64,
+    #[serde(default)]
+    pub feedname: String,
+    pub feed_type: Option<i32>,
+}
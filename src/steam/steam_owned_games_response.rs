@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct SteamOwnedGamesResponse {
+    pub response: SteamOwnedGames,
+}
+
+// Private libraries come back as an empty `response` object
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SteamOwnedGames {
+    pub game_count: Option<i32>,
+    #[serde(default)]
+    pub games: Vec<SteamOwnedGame>,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SteamOwnedGame {
+    pub appid: u32,
+    pub name: Option<String>,
+    pub img_icon_url: Option<String>,
+    #[serde(default)]
+    pub playtime_forever: i32,
+    #[serde(default)]
+    pub playtime_2weeks: i32,
+    pub rtime_last_played: Option<i64>,
+}
@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct SteamPlayerBansResponse {
+    pub players: Vec<SteamPlayerBans>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+#[allow(dead_code)]
+pub struct SteamPlayerBans {
+    pub steam_id: String,
+    pub community_banned: bool,
+    #[serde(rename = "VACBanned")]
+    pub vac_banned: bool,
+    #[serde(rename = "NumberOfVACBans")]
+    pub number_of_vac_bans: i32,
+    pub days_since_last_ban: i32,
+    pub number_of_game_bans: i32,
+    pub economy_ban: String,
+}
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct SteamPlayerCountResponse {
+    pub response: SteamPlayerCount,
+}
+
+// `result` is 1 on success, `player_count` is missing for apps without stats
+#[derive(Debug, Deserialize)]
+pub struct SteamPlayerCount {
+    pub player_count: Option<i32>,
+    pub result: i32,
+}
@@ -0,0 +1,37 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct SteamLevelResponse {
+    pub response: SteamLevel,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamLevel {
+    pub player_level: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamBadgesResponse {
+    pub response: SteamBadges,
+}
+
+// Private profiles come back as an empty `response` object
+#[derive(Debug, Deserialize)]
+pub struct SteamBadges {
+    #[serde(default)]
+    pub badges: Vec<SteamBadge>,
+    pub player_xp: Option<i32>,
+    pub player_level: Option<i32>,
+    pub player_xp_needed_to_level_up: Option<i32>,
+    pub player_xp_needed_current_level: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamBadge {
+    pub badgeid: i32,
+    pub appid: Option<i32>,
+    pub level: i32,
+    pub completion_time: i64,
+    pub xp: i32,
+    pub scarcity: i32,
+}
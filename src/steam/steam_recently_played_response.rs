@@ -0,0 +1,26 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct SteamRecentlyPlayedResponse {
+    pub response: SteamRecentlyPlayedGames,
+}
+
+// `total_count` is missing when the profile's game details are private
+#[derive(Debug, Deserialize)]
+pub struct SteamRecentlyPlayedGames {
+    pub total_count: Option<i32>,
+    #[serde(default)]
+    pub games: Vec<SteamRecentlyPlayedGame>,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SteamRecentlyPlayedGame {
+    pub appid: u32,
+    pub name: Option<String>,
+    pub img_icon_url: Option<String>,
+    #[serde(default)]
+    pub playtime_2weeks: i32,
+    #[serde(default)]
+    pub playtime_forever: i32,
+}
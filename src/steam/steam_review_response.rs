@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+// `success` is 1 when the summary could be built
+#[derive(Debug, Deserialize)]
+pub struct SteamReviewResponse {
+    pub success: i32,
+    pub query_summary: Option<SteamReviewSummary>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamReviewSummary {
+    pub review_score: i32,
+    pub review_score_desc: String,
+    pub total_positive: i32,
+    pub total_negative: i32,
+    pub total_reviews: i32,
+}
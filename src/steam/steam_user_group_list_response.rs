@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct SteamUserGroupListResponse {
+    pub response: SteamUserGroupList,
+}
+
+// Private profiles come back with `success: false` and no groups
+#[derive(Debug, Deserialize)]
+pub struct SteamUserGroupList {
+    pub success: bool,
+    #[serde(default)]
+    pub groups: Vec<SteamUserGroup>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SteamUserGroup {
+    pub gid: String,
+}